@@ -0,0 +1,542 @@
+use std::{
+  io::{self, ErrorKind, IsTerminal, Write},
+  path::PathBuf,
+  sync::Arc,
+};
+
+use clap::{Parser, Subcommand};
+use crossterm::{
+  event::{self, Event, KeyCode, KeyModifiers},
+  terminal,
+};
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+use crate::{
+  enums::{
+    backend::Backend, currency::CurrencyEnum, export_format::ExportFormat, month::MonthEnum,
+    transaction_type::TransactionType,
+  },
+  models::{custom_error::CustomError, transaction::TransactionModel},
+  requests::{api_client::ApiClient, auth},
+  save_config,
+  states::loader::fetch_month,
+  storage::{http::HttpStorage, local::LocalStorage, SharedStorage},
+  types::{
+    request::transaction::CreateTransactionRequest,
+    responses::{login::LoginRequest, transaction::TransactionBalances},
+    year_month::YearMonth,
+    money::Money,
+    Config,
+  },
+  utils::{export, transaction_utils},
+};
+
+use self::table::print_table;
+
+mod table;
+
+#[derive(Parser)]
+#[command(version, about = "Track expenses, incomes and savings")]
+pub struct Cli {
+  /// Print JSON instead of tables
+  #[arg(long, global = true)]
+  pub json: bool,
+  #[command(subcommand)]
+  pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+  /// List the transactions of a month
+  List {
+    /// Month to list (YYYY-MM), defaults to the current one
+    #[arg(long)]
+    month: Option<YearMonth>,
+    /// Only list transactions of this type, expenses include installments as in the TUI
+    #[arg(long = "type", value_enum)]
+    transaction_type: Option<TransactionType>,
+  },
+  /// Create a transaction
+  Add {
+    #[arg(long = "type", value_enum, default_value_t = TransactionType::EXPENSE)]
+    transaction_type: TransactionType,
+    #[arg(long)]
+    amount: Decimal,
+    #[arg(long, value_parser = parse_currency, default_value = "UYU")]
+    currency: CurrencyEnum,
+    #[arg(long)]
+    exchange_rate: Option<Decimal>,
+    /// Month of the transaction (YYYY-MM), defaults to the current one
+    #[arg(long)]
+    month: Option<YearMonth>,
+    #[arg(long)]
+    day: Option<u8>,
+    #[arg(long)]
+    note: Option<String>,
+    /// Category name or id
+    #[arg(long)]
+    category: Option<String>,
+  },
+  /// Show the balances of every month of a year
+  Summary {
+    /// Defaults to the current year
+    #[arg(long)]
+    year: Option<i32>,
+  },
+  /// Login and store the session in the config file
+  Login {
+    #[arg(long)]
+    email: Option<String>,
+  },
+  /// Export every month between `from` and `to` (YYYY-MM, inclusive)
+  Export {
+    #[arg(long)]
+    from: YearMonth,
+    #[arg(long)]
+    to: YearMonth,
+    #[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+    format: ExportFormat,
+    /// File to write, prints to stdout when missing
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+  },
+}
+
+#[derive(Serialize)]
+struct MonthSummary {
+  period: String,
+  balances: TransactionBalances,
+}
+
+fn parse_currency(value: &str) -> Result<CurrencyEnum, String> {
+  CurrencyEnum::from_string(value.to_string()).ok_or_else(|| {
+    format!(
+      "unknown currency {}, expected a code like UYU or USD",
+      value
+    )
+  })
+}
+
+fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<(), CustomError> {
+  writeln!(
+    io::stdout().lock(),
+    "{}",
+    serde_json::to_string_pretty(value)?
+  )?;
+
+  Ok(())
+}
+
+fn format_amount(amount: Decimal, currency: &CurrencyEnum) -> String {
+  Money::new(amount, currency.clone()).to_string()
+}
+
+/// Summary totals are expressed in UYU.
+fn format_total(amount: Decimal) -> String {
+  format!("{:.2}", Money::new(amount, CurrencyEnum::UYU).rounded())
+}
+
+/// Storage for commands running outside the TUI, the http backend uses the saved session.
+fn open_storage(config: &Config) -> Result<SharedStorage, CustomError> {
+  match config.backend {
+    Backend::Http => {
+      let api = ApiClient::from_config(config, reqwest::Client::new())?;
+
+      Ok(Arc::new(HttpStorage::new(api)))
+    }
+    Backend::Local => {
+      let path = match &config.data_path {
+        Some(path) => PathBuf::from(path),
+        None => LocalStorage::default_path(),
+      };
+
+      Ok(Arc::new(LocalStorage::open(path)?))
+    }
+  }
+}
+
+async fn list(
+  storage: SharedStorage,
+  period: YearMonth,
+  transaction_type: Option<TransactionType>,
+  json: bool,
+) -> Result<(), CustomError> {
+  let transactions: Vec<TransactionModel> = match transaction_type {
+    Some(TransactionType::EXPENSE) => {
+      let (expenses, installments) = tokio::join!(
+        storage.get_transactions_by_month_and_type(TransactionType::EXPENSE, &period),
+        storage.get_transactions_by_month_and_type(TransactionType::INSTALLMENTS, &period),
+      );
+
+      let mut expenses = expenses?;
+      expenses.extend(installments?);
+      transaction_utils::sort(&mut expenses);
+
+      expenses
+    }
+    Some(transaction_type) => {
+      storage
+        .get_transactions_by_month_and_type(transaction_type, &period)
+        .await?
+    }
+    None => {
+      let data = fetch_month(storage.as_ref(), &period).await?;
+
+      data
+        .incomes
+        .into_iter()
+        .chain(data.expenses)
+        .chain(data.savings)
+        .collect()
+    }
+  };
+
+  if json {
+    return print_json(&transactions);
+  }
+
+  let rows: Vec<Vec<String>> = transactions
+    .iter()
+    .map(|transaction| {
+      vec![
+        transaction
+          .day
+          .map(|day| day.to_string())
+          .unwrap_or_default(),
+        String::from(transaction.r#type.clone()),
+        format_amount(transaction.amount, &transaction.currency),
+        transaction
+          .category
+          .as_ref()
+          .map(|category| category.name.clone())
+          .unwrap_or_default(),
+        transaction.note.clone().unwrap_or_default(),
+      ]
+    })
+    .collect();
+
+  print_table(
+    &["Day", "Type", "Amount", "Category", "Note"],
+    &rows,
+    &[0, 2],
+  )?;
+
+  Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn add(
+  storage: SharedStorage,
+  transaction_type: TransactionType,
+  amount: Decimal,
+  currency: CurrencyEnum,
+  exchange_rate: Option<Decimal>,
+  period: YearMonth,
+  day: Option<u8>,
+  note: Option<String>,
+  category: Option<String>,
+  json: bool,
+) -> Result<(), CustomError> {
+  let invalid = |message: &str| CustomError::Validation(String::from(message));
+
+  if amount <= Decimal::ZERO {
+    return Err(invalid("Amount must be a positive number"));
+  }
+
+  if exchange_rate.is_some_and(|rate| rate <= Decimal::ZERO) {
+    return Err(invalid("Exchange rate must be a positive number"));
+  }
+
+  if let Some(day) = day {
+    period.validate_day(day).map_err(CustomError::Validation)?;
+  }
+
+  let year = u16::try_from(period.year).map_err(|_| invalid("Invalid year"))?;
+
+  let category_id = match category {
+    Some(category) => {
+      let categories = storage.get_categories().await?;
+
+      let found = categories
+        .iter()
+        .filter(|known| known.r#type == transaction_type)
+        .find(|known| known.category_id == category || known.name.eq_ignore_ascii_case(&category))
+        .ok_or_else(|| CustomError::NotFound(format!("Category {}", category)))?;
+
+      Some(found.category_id.clone())
+    }
+    None => None,
+  };
+
+  let transaction = storage
+    .create_transaction(CreateTransactionRequest {
+      exchange_rate: exchange_rate.filter(|_| currency.has_exchange_rate()),
+      r#type: transaction_type,
+      amount,
+      currency,
+      day,
+      month: period.month,
+      year,
+      note: note.filter(|note| !note.trim().is_empty()),
+      category_id,
+    })
+    .await?;
+
+  if json {
+    return print_json(&transaction);
+  }
+
+  writeln!(
+    io::stdout().lock(),
+    "Created {} {} ({})",
+    String::from(transaction.r#type.clone()),
+    format_amount(transaction.amount, &transaction.currency),
+    transaction.transaction_id
+  )?;
+
+  Ok(())
+}
+
+async fn summary(storage: SharedStorage, year: i32, json: bool) -> Result<(), CustomError> {
+  let mut periods: Vec<YearMonth> = storage
+    .get_month_by_year()
+    .await?
+    .into_iter()
+    .filter(|months| months.year == year.to_string())
+    .flat_map(|months| months.months)
+    .filter_map(MonthEnum::from_string)
+    .map(|month| YearMonth::new(year, month))
+    .collect();
+  periods.sort();
+
+  let mut months = vec![];
+
+  for period in periods {
+    let balances = storage.get_transactions_balances(&period).await?;
+    months.push(MonthSummary {
+      period: period.to_string(),
+      balances,
+    });
+  }
+
+  if json {
+    return print_json(&months);
+  }
+
+  let mut totals = TransactionBalances::default();
+  let mut rows: Vec<Vec<String>> = months
+    .iter()
+    .map(|month| {
+      let balances = &month.balances;
+      totals.incomes.total += balances.incomes.total;
+      totals.expenses.total += balances.expenses.total;
+      totals.savings.total += balances.savings.total;
+
+      vec![
+        month.period.clone(),
+        format_total(balances.incomes.total),
+        format_total(balances.expenses.total),
+        format_total(balances.savings.total),
+        format_total(balances.incomes.total - balances.expenses.total),
+      ]
+    })
+    .collect();
+
+  rows.push(vec![
+    String::from("Total"),
+    format_total(totals.incomes.total),
+    format_total(totals.expenses.total),
+    format_total(totals.savings.total),
+    format_total(totals.incomes.total - totals.expenses.total),
+  ]);
+
+  print_table(
+    &["Month", "Incomes", "Expenses", "Savings", "Balance"],
+    &rows,
+    &[1, 2, 3, 4],
+  )?;
+
+  Ok(())
+}
+
+fn prompt(label: &str) -> Result<String, CustomError> {
+  eprint!("{}: ", label);
+  io::stderr().flush()?;
+
+  let mut line = String::new();
+  io::stdin().read_line(&mut line)?;
+
+  Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// Reads the password without echo when attached to a terminal, scripts can pipe it instead.
+fn read_password() -> Result<String, CustomError> {
+  if !io::stdin().is_terminal() {
+    return prompt("Password");
+  }
+
+  eprint!("Password: ");
+  io::stderr().flush()?;
+
+  terminal::enable_raw_mode()?;
+  let password = read_hidden();
+  terminal::disable_raw_mode()?;
+  eprintln!();
+
+  password
+}
+
+fn read_hidden() -> Result<String, CustomError> {
+  let mut password = String::new();
+
+  loop {
+    if let Event::Key(key) = event::read()? {
+      match key.code {
+        KeyCode::Enter => return Ok(password),
+        KeyCode::Esc => break,
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break,
+        KeyCode::Char(c) => password.push(c),
+        KeyCode::Backspace => {
+          password.pop();
+        }
+        _ => (),
+      }
+    }
+  }
+
+  Err(CustomError::Io(io::Error::new(
+    ErrorKind::Interrupted,
+    "Login cancelled",
+  )))
+}
+
+async fn login(mut config: Config, email: Option<String>) -> Result<(), CustomError> {
+  if config.backend != Backend::Http {
+    return Err(CustomError::Config(String::from(
+      "login is only needed with the http backend",
+    )));
+  }
+
+  let api = ApiClient::from_config(&config, reqwest::Client::new())?;
+
+  let email = match email {
+    Some(email) => email,
+    None => prompt("Email")?,
+  };
+  let password = read_password()?;
+
+  let response = auth::login(
+    &api,
+    LoginRequest {
+      email: email.trim().to_string(),
+      password,
+    },
+  )
+  .await?;
+
+  config.session_id = Some(response.token);
+  config.user_id = Some(response.user.user_id.clone());
+  save_config(&config)?;
+
+  eprintln!("Logged in as {}", response.user.email);
+
+  Ok(())
+}
+
+async fn export_range(
+  storage: SharedStorage,
+  from: YearMonth,
+  to: YearMonth,
+  format: ExportFormat,
+  output: Option<PathBuf>,
+) -> Result<(), CustomError> {
+  if from > to {
+    return Err(CustomError::Validation(format!(
+      "{} is after {}, nothing to export",
+      from, to
+    )));
+  }
+
+  let mut months = vec![];
+  let mut period = from;
+
+  while period <= to {
+    let data = fetch_month(storage.as_ref(), &period).await?;
+    let next = period.next();
+    months.push((period, data));
+    period = next;
+  }
+
+  match output {
+    Some(path) => {
+      export::write(&path, &format, &months)?;
+      eprintln!("Exported {} months to {}", months.len(), path.display());
+    }
+    None => io::stdout().write_all(export::render(&format, &months)?.as_bytes())?,
+  }
+
+  Ok(())
+}
+
+pub async fn run(command: Command, config: Config, json: bool) -> Result<(), CustomError> {
+  let result = match command {
+    Command::List {
+      month,
+      transaction_type,
+    } => {
+      list(
+        open_storage(&config)?,
+        month.unwrap_or_default(),
+        transaction_type,
+        json,
+      )
+      .await
+    }
+    Command::Add {
+      transaction_type,
+      amount,
+      currency,
+      exchange_rate,
+      month,
+      day,
+      note,
+      category,
+    } => {
+      add(
+        open_storage(&config)?,
+        transaction_type,
+        amount,
+        currency,
+        exchange_rate,
+        month.unwrap_or_default(),
+        day,
+        note,
+        category,
+        json,
+      )
+      .await
+    }
+    Command::Summary { year } => {
+      summary(
+        open_storage(&config)?,
+        year.unwrap_or_else(|| YearMonth::now().year),
+        json,
+      )
+      .await
+    }
+    Command::Export {
+      from,
+      to,
+      format,
+      output,
+    } => export_range(open_storage(&config)?, from, to, format, output).await,
+    Command::Login { email } => login(config, email).await,
+  };
+
+  // The reader went away, e.g. `expenses list | head`, there is nothing left to report.
+  match result {
+    Err(CustomError::Io(err)) if err.kind() == ErrorKind::BrokenPipe => Ok(()),
+    result => result,
+  }
+}
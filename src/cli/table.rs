@@ -0,0 +1,55 @@
+use std::io::{self, Write};
+
+/// Prints rows as aligned plain text columns, `right` lists the columns aligned to the right.
+pub fn print_table(headers: &[&str], rows: &[Vec<String>], right: &[usize]) -> io::Result<()> {
+  let mut widths: Vec<usize> = headers
+    .iter()
+    .map(|header| header.chars().count())
+    .collect();
+
+  for row in rows {
+    for (index, cell) in row.iter().enumerate() {
+      widths[index] = widths[index].max(cell.chars().count());
+    }
+  }
+
+  let format_row = |cells: Vec<&str>| {
+    cells
+      .iter()
+      .enumerate()
+      .map(|(index, cell)| {
+        if right.contains(&index) {
+          format!("{:>width$}", cell, width = widths[index])
+        } else {
+          format!("{:<width$}", cell, width = widths[index])
+        }
+      })
+      .collect::<Vec<String>>()
+      .join("  ")
+      .trim_end()
+      .to_string()
+  };
+
+  let mut stdout = io::stdout().lock();
+
+  writeln!(stdout, "{}", format_row(headers.to_vec()))?;
+  writeln!(
+    stdout,
+    "{}",
+    widths
+      .iter()
+      .map(|width| "-".repeat(*width))
+      .collect::<Vec<String>>()
+      .join("  ")
+  )?;
+
+  for row in rows {
+    writeln!(
+      stdout,
+      "{}",
+      format_row(row.iter().map(String::as_str).collect())
+    )?;
+  }
+
+  Ok(())
+}
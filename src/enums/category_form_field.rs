@@ -0,0 +1,6 @@
+#[derive(Debug, PartialEq)]
+pub enum CategoryFormField {
+  Name,
+  Type,
+  Note,
+}
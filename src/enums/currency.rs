@@ -1,19 +1,190 @@
-use std::convert::From;
+use std::{convert::From, fmt};
 use serde::{Serialize, Deserialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Active ISO-4217 codes, sorted. Test and "no currency" codes are left out.
+const ISO_CODES: [&str; 180] = [
+  "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT", "BGN",
+  "BHD", "BIF", "BMD", "BND", "BOB", "BOV", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD", "CAD", "CDF",
+  "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUC", "CUP", "CVE", "CZK", "DJF",
+  "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL", "GHS", "GIP", "GMD",
+  "GNF", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS", "INR", "IQD", "IRR", "ISK", "JMD",
+  "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW", "KWD", "KYD", "KZT", "LAK", "LBP", "LKR",
+  "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT", "MOP", "MRU", "MUR", "MVR", "MWK",
+  "MXN", "MXV", "MYR", "MZN", "NAD", "NGN", "NIO", "NOK", "NPR", "NZD", "OMR", "PAB", "PEN", "PGK",
+  "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD", "RUB", "RWF", "SAR", "SBD", "SCR", "SDG", "SEK",
+  "SGD", "SHP", "SLE", "SLL", "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT",
+  "TND", "TOP", "TRY", "TTD", "TWD", "TZS", "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS",
+  "VED", "VES", "VND", "VUV", "WST", "XAF", "XAG", "XAU", "XBA", "XBB", "XBC", "XBD", "XCD", "XCG",
+  "XDR", "XOF", "XPD", "XPF", "XPT", "XSU", "XUA", "YER", "ZAR", "ZMW", "ZWG", "ZWL",
+];
+
+/// ISO-4217 currency, codes the app doesn't know about are kept as `Other`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[serde(try_from = "String", into = "String")]
 pub enum CurrencyEnum {
-  USD,
+  #[default]
   UYU,
+  USD,
   EUR,
+  ARS,
+  BRL,
+  Other(String),
+}
+
+impl CurrencyEnum {
+  /// Currencies offered when cycling through a select, in display order.
+  pub const KNOWN: [CurrencyEnum; 5] = [
+    CurrencyEnum::UYU,
+    CurrencyEnum::USD,
+    CurrencyEnum::EUR,
+    CurrencyEnum::ARS,
+    CurrencyEnum::BRL,
+  ];
+
+  fn known_index(&self) -> Option<usize> {
+    CurrencyEnum::KNOWN.iter().position(|known| known == self)
+  }
+
+  pub fn next(&self) -> Self {
+    match self.known_index() {
+      Some(index) => CurrencyEnum::KNOWN[(index + 1) % CurrencyEnum::KNOWN.len()].clone(),
+      None => CurrencyEnum::UYU,
+    }
+  }
+
+  pub fn previous(&self) -> Self {
+    match self.known_index() {
+      Some(index) => {
+        let len = CurrencyEnum::KNOWN.len();
+        CurrencyEnum::KNOWN[(index + len - 1) % len].clone()
+      }
+      None => CurrencyEnum::UYU,
+    }
+  }
+
+  /// Parses user input, accepting unambiguous symbols and ISO-4217 codes.
+  /// A bare `$` is rejected since many currencies use it.
+  pub fn from_string(currency: String) -> Option<Self> {
+    match currency.trim().to_uppercase().as_str() {
+      "US$" | "U$S" => Some(CurrencyEnum::USD),
+      "$U" => Some(CurrencyEnum::UYU),
+      "€" => Some(CurrencyEnum::EUR),
+      "R$" => Some(CurrencyEnum::BRL),
+      code => CurrencyEnum::from_code(code),
+    }
+  }
+
+  /// Parses an ISO-4217 code in any case, three letters that aren't one are rejected.
+  pub fn from_code(code: &str) -> Option<Self> {
+    let code = code.trim().to_uppercase();
+
+    if ISO_CODES.binary_search(&code.as_str()).is_err() {
+      return None;
+    }
+
+    Some(match code.as_str() {
+      "USD" => CurrencyEnum::USD,
+      "UYU" => CurrencyEnum::UYU,
+      "EUR" => CurrencyEnum::EUR,
+      "ARS" => CurrencyEnum::ARS,
+      "BRL" => CurrencyEnum::BRL,
+      _ => CurrencyEnum::Other(code),
+    })
+  }
+
+  pub fn code(&self) -> &str {
+    match self {
+      CurrencyEnum::USD => "USD",
+      CurrencyEnum::UYU => "UYU",
+      CurrencyEnum::EUR => "EUR",
+      CurrencyEnum::ARS => "ARS",
+      CurrencyEnum::BRL => "BRL",
+      CurrencyEnum::Other(code) => code,
+    }
+  }
+
+  /// Decimal places of the currency's minor unit as defined by ISO-4217.
+  pub fn minor_units(&self) -> u32 {
+    match self.code() {
+      "CLP" | "ISK" | "JPY" | "KRW" | "PYG" | "UYI" | "VND" => 0,
+      "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+      _ => 2,
+    }
+  }
+
+  pub fn has_exchange_rate(&self) -> bool {
+    *self != CurrencyEnum::UYU
+  }
+}
+
+impl TryFrom<String> for CurrencyEnum {
+  type Error = String;
+
+  fn try_from(code: String) -> Result<Self, Self::Error> {
+    CurrencyEnum::from_code(&code).ok_or_else(|| format!("unknown currency {}", code))
+  }
 }
 
 impl From<CurrencyEnum> for String {
   fn from(currency: CurrencyEnum) -> Self {
-    match currency {
-      CurrencyEnum::USD => "USD".to_string(),
-      CurrencyEnum::UYU => "UYU".to_string(),
-      CurrencyEnum::EUR => "EUR".to_string(),
-    }
+    currency.code().to_string()
+  }
+}
+
+impl fmt::Display for CurrencyEnum {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.code())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn from_string_accepts_codes_and_unambiguous_symbols() {
+    assert_eq!(
+      CurrencyEnum::from_string(String::from(" usd ")),
+      Some(CurrencyEnum::USD)
+    );
+    assert_eq!(
+      CurrencyEnum::from_string(String::from("U$S")),
+      Some(CurrencyEnum::USD)
+    );
+    assert_eq!(
+      CurrencyEnum::from_string(String::from("$U")),
+      Some(CurrencyEnum::UYU)
+    );
+    assert_eq!(
+      CurrencyEnum::from_string(String::from("gbp")),
+      Some(CurrencyEnum::Other(String::from("GBP")))
+    );
+  }
+
+  #[test]
+  fn from_string_rejects_ambiguous_symbols() {
+    assert_eq!(CurrencyEnum::from_string(String::from("$")), None);
+    assert_eq!(CurrencyEnum::from_string(String::from("US")), None);
+    assert_eq!(CurrencyEnum::from_string(String::from("U5D")), None);
+  }
+
+  #[test]
+  fn from_code_only_accepts_iso_codes() {
+    assert_eq!(CurrencyEnum::from_code("brl"), Some(CurrencyEnum::BRL));
+    assert_eq!(
+      CurrencyEnum::from_code("JPY"),
+      Some(CurrencyEnum::Other(String::from("JPY")))
+    );
+    assert_eq!(CurrencyEnum::from_code("sum"), None);
+    assert_eq!(CurrencyEnum::from_code("ABC"), None);
+  }
+
+  #[test]
+  fn deserialize_rejects_unknown_codes() {
+    assert_eq!(
+      serde_json::from_str::<CurrencyEnum>(r#""uyu""#).unwrap(),
+      CurrencyEnum::UYU
+    );
+    assert!(serde_json::from_str::<CurrencyEnum>(r#""XYZ""#).is_err());
   }
 }
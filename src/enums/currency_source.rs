@@ -0,0 +1,8 @@
+use super::currency::CurrencyEnum;
+
+/// Where an imported row takes its currency from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CurrencySource {
+  Fixed(CurrencyEnum),
+  Column(usize),
+}
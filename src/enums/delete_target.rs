@@ -0,0 +1,11 @@
+use crate::{
+  models::{category::CategoryModel, transaction::TransactionModel},
+  utils::recurring::RecurringTemplate,
+};
+
+#[derive(Debug)]
+pub enum DeleteTarget {
+  Transaction(Box<TransactionModel>),
+  Category(Box<CategoryModel>),
+  Recurring(Box<RecurringTemplate>),
+}
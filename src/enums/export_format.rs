@@ -0,0 +1,34 @@
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, PartialEq, ValueEnum)]
+pub enum ExportFormat {
+  Csv,
+  Json,
+  Markdown,
+}
+
+impl ExportFormat {
+  pub fn next(&self) -> Self {
+    match self {
+      ExportFormat::Csv => ExportFormat::Json,
+      ExportFormat::Json => ExportFormat::Markdown,
+      ExportFormat::Markdown => ExportFormat::Csv,
+    }
+  }
+
+  pub fn previous(&self) -> Self {
+    match self {
+      ExportFormat::Csv => ExportFormat::Markdown,
+      ExportFormat::Json => ExportFormat::Csv,
+      ExportFormat::Markdown => ExportFormat::Json,
+    }
+  }
+
+  pub fn extension(&self) -> &'static str {
+    match self {
+      ExportFormat::Csv => "csv",
+      ExportFormat::Json => "json",
+      ExportFormat::Markdown => "md",
+    }
+  }
+}
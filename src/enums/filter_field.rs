@@ -0,0 +1,9 @@
+#[derive(Debug, PartialEq)]
+pub enum FilterField {
+  Text,
+  Category,
+  Min,
+  Max,
+  Currency,
+  Days,
+}
@@ -0,0 +1,27 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub enum Frequency {
+  WEEKLY,
+  #[default]
+  MONTHLY,
+  YEARLY,
+}
+
+impl Frequency {
+  pub fn next(&self) -> Self {
+    match self {
+      Frequency::WEEKLY => Frequency::MONTHLY,
+      Frequency::MONTHLY => Frequency::YEARLY,
+      Frequency::YEARLY => Frequency::WEEKLY,
+    }
+  }
+
+  pub fn previous(&self) -> Self {
+    match self {
+      Frequency::WEEKLY => Frequency::YEARLY,
+      Frequency::MONTHLY => Frequency::WEEKLY,
+      Frequency::YEARLY => Frequency::MONTHLY,
+    }
+  }
+}
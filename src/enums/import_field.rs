@@ -0,0 +1,8 @@
+#[derive(Debug, PartialEq)]
+pub enum ImportField {
+  Date,
+  Amount,
+  Currency,
+  Note,
+  Type,
+}
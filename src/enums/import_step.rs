@@ -0,0 +1,6 @@
+#[derive(Debug, PartialEq)]
+pub enum ImportStep {
+  File,
+  Mapping,
+  Preview,
+}
@@ -0,0 +1,49 @@
+use crate::{
+  enums::delete_target::DeleteTarget,
+  models::{category::CategoryModel, custom_error::CustomError, transaction::TransactionModel},
+  states::loader::MonthData,
+  types::{responses::transaction::TransactionBalances, year_month::YearMonth},
+};
+
+pub enum LoaderMessage {
+  Month {
+    request_id: u64,
+    period: YearMonth,
+    result: Result<MonthData, CustomError>,
+  },
+  Categories(Result<Vec<CategoryModel>, CustomError>),
+  Trends(Result<Vec<(YearMonth, TransactionBalances)>, CustomError>),
+  Installments(Result<Vec<TransactionModel>, CustomError>),
+  /// The months that failed to load are left out, `error` is the first failure.
+  Year {
+    year: i32,
+    balances: Vec<(YearMonth, TransactionBalances)>,
+    error: Option<CustomError>,
+  },
+  Health(bool),
+  /// `synced` operations from the front of the queue were sent.
+  Replayed {
+    synced: usize,
+    error: Option<CustomError>,
+  },
+  Imported {
+    created: usize,
+    error: Option<CustomError>,
+  },
+  Generated {
+    created: usize,
+    error: Option<CustomError>,
+  },
+  TransactionSaved {
+    transaction_id: Option<String>,
+    result: Result<Box<TransactionModel>, CustomError>,
+  },
+  CategorySaved {
+    category_id: Option<String>,
+    result: Result<CategoryModel, CustomError>,
+  },
+  Deleted {
+    target: DeleteTarget,
+    result: Result<(), CustomError>,
+  },
+}
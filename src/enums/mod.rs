@@ -1,4 +1,23 @@
+pub mod backend;
+pub mod category_form_field;
 pub mod currency;
+pub mod currency_source;
+pub mod delete_target;
+pub mod export_field;
+pub mod export_format;
+pub mod filter_field;
+pub mod frequency;
+pub mod import_field;
+pub mod import_step;
+pub mod language;
+pub mod loader_message;
+pub mod login_field;
 pub mod month;
+pub mod notification_level;
+pub mod pending_operation;
+pub mod recurring_form_field;
 pub mod selected_block;
+pub mod sign_up_field;
+pub mod tab;
+pub mod transaction_form_field;
 pub mod transaction_type;
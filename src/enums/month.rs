@@ -1,7 +1,7 @@
 use std::convert::From;
 use serde::{Serialize, Deserialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum MonthEnum {
   JANUARY,
   FEBRUARY,
@@ -36,9 +36,34 @@ impl MonthEnum {
     }
   }
 
+  pub fn to_u32(&self) -> u32 {
+    match self {
+      MonthEnum::JANUARY => 1,
+      MonthEnum::FEBRUARY => 2,
+      MonthEnum::MARCH => 3,
+      MonthEnum::APRIL => 4,
+      MonthEnum::MAY => 5,
+      MonthEnum::JUNE => 6,
+      MonthEnum::JULY => 7,
+      MonthEnum::AUGUST => 8,
+      MonthEnum::SEPTEMBER => 9,
+      MonthEnum::OCTOBER => 10,
+      MonthEnum::NOVEMBER => 11,
+      MonthEnum::DECEMBER => 12,
+    }
+  }
+
+  pub fn next(&self) -> Self {
+    MonthEnum::from_u32(self.to_u32() % 12 + 1).unwrap_or(MonthEnum::JANUARY)
+  }
+
+  pub fn previous(&self) -> Self {
+    MonthEnum::from_u32((self.to_u32() + 10) % 12 + 1).unwrap_or(MonthEnum::DECEMBER)
+  }
+
   pub fn from_string(month: String) -> Option<Self> {
-    match month.as_str() {
-      "January" => Some(MonthEnum::JANUARY),
+    match month.trim().to_uppercase().as_str() {
+      "JANUARY" => Some(MonthEnum::JANUARY),
       "FEBRUARY" => Some(MonthEnum::FEBRUARY),
       "MARCH" => Some(MonthEnum::MARCH),
       "APRIL" => Some(MonthEnum::APRIL),
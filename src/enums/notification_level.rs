@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotificationLevel {
+  Info,
+  Warn,
+  Error,
+}
+
+impl NotificationLevel {
+  pub fn duration(&self) -> Duration {
+    match self {
+      NotificationLevel::Info => Duration::from_secs(3),
+      NotificationLevel::Warn => Duration::from_secs(5),
+      NotificationLevel::Error => Duration::from_secs(8),
+    }
+  }
+}
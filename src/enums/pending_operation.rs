@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::request::transaction::{CreateTransactionRequest, UpdateTransactionRequest};
+
+/// A change made while offline, replayed against the server on reconnect.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum PendingOperation {
+  CreateTransaction(CreateTransactionRequest),
+  UpdateTransaction {
+    transaction_id: String,
+    request: UpdateTransactionRequest,
+  },
+}
@@ -0,0 +1,11 @@
+#[derive(Debug, PartialEq)]
+pub enum RecurringFormField {
+  Type,
+  Amount,
+  Currency,
+  Category,
+  Frequency,
+  Start,
+  End,
+  Note,
+}
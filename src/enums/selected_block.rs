@@ -1,4 +1,4 @@
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum SelectedBlock {
   Months,
   Incomes,
@@ -6,4 +6,9 @@ pub enum SelectedBlock {
   Expenses,
   Tabs,
   Details,
+  Categories,
+  Budgets,
+  Charts,
+  Installments,
+  Recurring,
 }
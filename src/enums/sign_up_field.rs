@@ -0,0 +1,8 @@
+#[derive(Debug, PartialEq)]
+pub enum SignUpField {
+  Email,
+  FirstName,
+  LastName,
+  Password,
+  ConfirmPassword,
+}
@@ -0,0 +1,41 @@
+/// Tabs of the main screen, in the order they are shown.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tab {
+  All,
+  Expenses,
+  Incomes,
+  Savings,
+  Categories,
+  Budgets,
+  Charts,
+  Installments,
+  Recurring,
+}
+
+impl Tab {
+  pub const ALL: [Tab; 9] = [
+    Tab::All,
+    Tab::Expenses,
+    Tab::Incomes,
+    Tab::Savings,
+    Tab::Categories,
+    Tab::Budgets,
+    Tab::Charts,
+    Tab::Installments,
+    Tab::Recurring,
+  ];
+
+  pub fn title(&self) -> &'static str {
+    match self {
+      Tab::All => "All",
+      Tab::Expenses => "Expenses",
+      Tab::Incomes => "Incomes",
+      Tab::Savings => "Savings",
+      Tab::Categories => "Categories",
+      Tab::Budgets => "Budgets",
+      Tab::Charts => "Charts",
+      Tab::Installments => "Installments",
+      Tab::Recurring => "Recurring",
+    }
+  }
+}
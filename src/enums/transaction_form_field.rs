@@ -0,0 +1,12 @@
+#[derive(Debug, PartialEq)]
+pub enum TransactionFormField {
+  Type,
+  Amount,
+  Currency,
+  ExchangeRate,
+  Category,
+  Day,
+  Month,
+  Year,
+  Note,
+}
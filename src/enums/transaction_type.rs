@@ -1,7 +1,8 @@
 use std::convert::From;
+use clap::ValueEnum;
 use serde::{Serialize, Deserialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ValueEnum)]
 pub enum TransactionType {
   INCOME,
   EXPENSE,
@@ -9,6 +10,26 @@ pub enum TransactionType {
   INSTALLMENTS,
 }
 
+impl TransactionType {
+  pub fn next(&self) -> Self {
+    match self {
+      TransactionType::EXPENSE => TransactionType::INCOME,
+      TransactionType::INCOME => TransactionType::SAVING,
+      TransactionType::SAVING => TransactionType::INSTALLMENTS,
+      TransactionType::INSTALLMENTS => TransactionType::EXPENSE,
+    }
+  }
+
+  pub fn previous(&self) -> Self {
+    match self {
+      TransactionType::EXPENSE => TransactionType::INSTALLMENTS,
+      TransactionType::INCOME => TransactionType::EXPENSE,
+      TransactionType::SAVING => TransactionType::INCOME,
+      TransactionType::INSTALLMENTS => TransactionType::SAVING,
+    }
+  }
+}
+
 impl From<TransactionType> for String {
   fn from(transaction_type: TransactionType) -> Self {
     match transaction_type {
@@ -1,9 +1,10 @@
+use clap::Parser;
 use dotenv::dotenv;
 use std::env;
 use std::{
   io::{self, BufReader},
-  error,
   fs::File,
+  path::Path,
 };
 use crossterm::{
   terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
@@ -12,19 +13,27 @@ use crossterm::{
 use tui::{Terminal, backend::CrosstermBackend};
 use types::Config;
 
-use crate::requests::health;
+use crate::{
+  cli::Cli,
+  enums::backend::Backend,
+  models::custom_error::CustomError,
+  requests::{api_client::ApiClient, health},
+  utils::{cache::Cache, fs::write_private},
+};
 
+pub mod cli;
 pub mod enums;
 pub mod models;
 pub mod requests;
 pub mod states;
+pub mod storage;
 pub mod types;
 pub mod ui;
 pub mod utils;
 
-fn load_config() -> io::Result<Config> {
+fn config_path() -> String {
   let home = env::var("HOME").unwrap();
-  let path = match env::var("ENV") {
+  match env::var("ENV") {
     Ok(env) => {
       if let "DEV" = &*env {
         String::from("config.dev.json")
@@ -33,32 +42,85 @@ fn load_config() -> io::Result<Config> {
       }
     }
     Err(_) => format!("{}/.config/expenses/{}", home, "config.json"),
-  };
+  }
+}
 
-  let file = File::open(path)?;
+fn load_config() -> Result<Config, CustomError> {
+  let path = config_path();
+  let file = File::open(&path)
+    .map_err(|err| CustomError::Config(format!("unable to open {}: {}", path, err)))?;
   let reader = BufReader::new(file);
-  let data: Config = serde_json::from_reader(reader)?;
+  let data: Config = serde_json::from_reader(reader)
+    .map_err(|err| CustomError::Config(format!("unable to parse {}: {}", path, err)))?;
   Ok(data)
 }
 
+fn save_config(config: &Config) -> Result<(), CustomError> {
+  write_private(
+    Path::new(&config_path()),
+    serde_json::to_string_pretty(config)?.as_bytes(),
+  )?;
+
+  Ok(())
+}
+
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn error::Error>> {
+async fn main() -> Result<(), CustomError> {
   dotenv().ok();
-  let client = reqwest::Client::new();
 
-  let ping = health::request(&client).await;
-  if ping.is_err() {
-    println!("{}", String::from("Error connect to server"));
-    std::process::exit(1)
+  let cli = Cli::parse();
+
+  let config = match load_config() {
+    Ok(config) => config,
+    Err(err) => {
+      println!("Error to load config: {}", err);
+      std::process::exit(1)
+    }
+  };
+
+  if let Some(command) = cli.command {
+    let language = config.language.clone();
+
+    if let Err(err) = cli::run(command, config, cli.json).await {
+      eprintln!("{}", err.message(&language));
+      std::process::exit(1)
+    }
+
+    return Ok(());
   }
 
+  let remote = match config.backend {
+    Backend::Http => {
+      let api = ApiClient::from_config(&config, reqwest::Client::new())?;
+
+      let online = match health::request(&api).await {
+        Ok(true) => true,
+        _ if Cache::load(&config).has_data() => false,
+        Ok(false) => {
+          println!("Error connect to server");
+          std::process::exit(1)
+        }
+        Err(err) => {
+          println!("Error connect to server: {}", err.message(&config.language));
+          std::process::exit(1)
+        }
+      };
+
+      Some((api, online))
+    }
+    Backend::Local => None,
+  };
+
   terminal::enable_raw_mode()?;
   let mut stdout = io::stdout();
   execute!(stdout, EnterAlternateScreen)?;
   let backend = CrosstermBackend::new(stdout);
   let mut terminal = Terminal::new(backend)?;
 
-  let res = ui::run_app(&mut terminal, &client).await;
+  let res = match remote {
+    Some((api, online)) => ui::run_app(&mut terminal, config, api, online).await,
+    None => ui::run_local(&mut terminal, &config).await,
+  };
 
   terminal::disable_raw_mode()?;
   execute!(terminal.backend_mut(), LeaveAlternateScreen,)?;
@@ -66,7 +128,7 @@ async fn main() -> Result<(), Box<dyn error::Error>> {
   terminal.show_cursor()?;
 
   if let Err(err) = res {
-    println!("{:?}", err)
+    println!("{}", err)
   }
 
   Ok(())
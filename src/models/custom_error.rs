@@ -1,39 +1,103 @@
 use std::error::Error;
 use std::fmt::*;
+use std::io;
 
-use crate::types::responses::response::ShowMessage;
+use crate::{enums::language::Language, types::responses::response::ShowMessage};
 
 #[derive(Debug)]
-pub struct CustomError<T: Debug> {
-  pub data: Option<T>,
-  pub message: Option<String>,
-  pub show_message: Option<ShowMessage>,
-}
-
-impl<T: Debug> CustomError<T> {
-  pub fn new(
-    data: Option<T>,
+pub enum CustomError {
+  Network(reqwest::Error),
+  Unauthorized,
+  Server {
+    status: u16,
     message: Option<String>,
     show_message: Option<ShowMessage>,
-  ) -> CustomError<T> {
-    CustomError {
-      data,
-      message,
-      show_message,
+  },
+  Decode(String),
+  Config(String),
+  NotFound(String),
+  /// Input rejected before reaching the storage.
+  Validation(String),
+  Io(io::Error),
+  /// A background task panicked or was cancelled.
+  Task(String),
+}
+
+impl CustomError {
+  pub fn message(&self, language: &Language) -> String {
+    match self {
+      CustomError::Network(err) => format!("Error connecting to server: {}", err),
+      CustomError::Unauthorized => String::from("Session expired, please login again"),
+      CustomError::Server {
+        status,
+        message,
+        show_message,
+      } => match (show_message, message) {
+        (Some(show_message), _) => match language {
+          Language::EN => show_message.en.clone(),
+          Language::ES => show_message.es.clone(),
+        },
+        (None, Some(message)) => message.clone(),
+        (None, None) => format!("Server error ({})", status),
+      },
+      CustomError::Decode(err) => format!("Invalid response from server: {}", err),
+      CustomError::Config(err) => format!("Config error: {}", err),
+      CustomError::NotFound(resource) => format!("{} not found", resource),
+      CustomError::Validation(message) => message.clone(),
+      CustomError::Io(err) => err.to_string(),
+      CustomError::Task(err) => format!("Background task failed: {}", err),
     }
   }
+
+  pub fn is_unauthorized(&self) -> bool {
+    matches!(self, CustomError::Unauthorized)
+  }
+
+  pub fn is_network(&self) -> bool {
+    matches!(self, CustomError::Network(_))
+  }
 }
 
-impl<T: Debug> Display for CustomError<T> {
+impl Display for CustomError {
   fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-    match &self.show_message {
-      Some(show_message) => write!(f, "{:?}", show_message.en),
-      None => match &self.message {
-        Some(data) => write!(f, "{:?}", data),
-        None => write!(f, "{:?}", "Generic error"),
-      },
+    write!(f, "{}", self.message(&Language::EN))
+  }
+}
+
+impl Error for CustomError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match self {
+      CustomError::Network(err) => Some(err),
+      CustomError::Io(err) => Some(err),
+      _ => None,
     }
   }
 }
 
-impl<T: Debug> Error for CustomError<T> {}
+impl From<reqwest::Error> for CustomError {
+  fn from(err: reqwest::Error) -> Self {
+    if err.is_decode() {
+      CustomError::Decode(err.to_string())
+    } else {
+      CustomError::Network(err)
+    }
+  }
+}
+
+impl From<serde_json::Error> for CustomError {
+  fn from(err: serde_json::Error) -> Self {
+    CustomError::Decode(err.to_string())
+  }
+}
+
+impl From<io::Error> for CustomError {
+  fn from(err: io::Error) -> Self {
+    CustomError::Io(err)
+  }
+}
+
+impl From<tokio::task::JoinError> for CustomError {
+  fn from(err: tokio::task::JoinError) -> Self {
+    CustomError::Task(err.to_string())
+  }
+}
@@ -1,5 +1,7 @@
+use rust_decimal::Decimal;
 use serde::{Serialize, Deserialize};
 use crate::enums::{month::MonthEnum, currency::CurrencyEnum, transaction_type::TransactionType};
+use crate::types::{money::Money, year_month::YearMonth};
 use super::{user::UserModel, category::CategoryModel};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -7,15 +9,25 @@ use super::{user::UserModel, category::CategoryModel};
 pub struct TransactionModel {
   pub transaction_id: String,
   pub r#type: TransactionType,
-  pub amount: f64,
+  pub amount: Decimal,
   pub currency: CurrencyEnum,
   pub note: Option<String>,
   pub day: Option<u8>,
   pub month: MonthEnum,
   pub year: u16,
-  pub exchange_rate: Option<f64>,
+  pub exchange_rate: Option<Decimal>,
   pub user_id: String,
   pub user: Option<UserModel>,
   pub category_id: String,
   pub category: Option<CategoryModel>,
 }
+
+impl TransactionModel {
+  pub fn money(&self) -> Money {
+    Money::new(self.amount, self.currency.clone())
+  }
+
+  pub fn period(&self) -> YearMonth {
+    YearMonth::new(self.year as i32, self.month.clone())
+  }
+}
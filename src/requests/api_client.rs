@@ -0,0 +1,163 @@
+use reqwest::{
+  Method, RequestBuilder, StatusCode, Url,
+  header::{HeaderValue, COOKIE},
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+  models::custom_error::CustomError,
+  types::{responses::response::CustomResponse, Config},
+};
+
+#[derive(Clone, Debug)]
+pub struct ApiClient {
+  pub base_url: Url,
+  pub session_id: Option<String>,
+  client: reqwest::Client,
+}
+
+impl ApiClient {
+  pub fn new(base_url: Url, client: reqwest::Client) -> Self {
+    Self {
+      base_url,
+      session_id: None,
+      client,
+    }
+  }
+
+  pub fn from_config(config: &Config, client: reqwest::Client) -> Result<Self, CustomError> {
+    let base_url = Url::parse(&config.url)
+      .map_err(|err| CustomError::Config(format!("invalid url {}: {}", config.url, err)))?;
+    let mut api = Self::new(base_url, client);
+    api.session_id = config.session_id.clone();
+
+    Ok(api)
+  }
+
+  pub fn set_session(&mut self, session_id: Option<String>) {
+    self.session_id = session_id;
+  }
+
+  pub fn url(&self, endpoint: &str) -> Url {
+    let mut url = self.base_url.clone();
+    url.set_path((self.base_url.path().trim_end_matches('/').to_owned() + endpoint).as_str());
+
+    url
+  }
+
+  fn request(&self, method: Method, endpoint: &str) -> Result<RequestBuilder, CustomError> {
+    let mut request = self.client.request(method, self.url(endpoint));
+
+    if let Some(session_id) = &self.session_id {
+      request = request.header(
+        COOKIE,
+        HeaderValue::from_str(&format!("sessionID={}", session_id))
+          .map_err(|_| CustomError::Config(String::from("invalid session id")))?,
+      );
+    }
+
+    Ok(request)
+  }
+
+  async fn send(&self, request: RequestBuilder) -> Result<reqwest::Response, CustomError> {
+    let response = request.send().await?;
+    let status = response.status();
+
+    // Without a session (e.g. logging in) a 401 is a plain rejection, keep the server's message.
+    if (status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN)
+      && self.session_id.is_some()
+    {
+      return Err(CustomError::Unauthorized);
+    }
+
+    if !status.is_success() {
+      let body = response.json::<CustomResponse<String>>().await.ok();
+
+      if status == StatusCode::NOT_FOUND {
+        return Err(CustomError::NotFound(
+          body
+            .and_then(|body| body.message)
+            .unwrap_or_else(|| String::from("Resource")),
+        ));
+      }
+
+      return Err(match body {
+        Some(body) => CustomError::Server {
+          status: status.as_u16(),
+          message: body.message,
+          show_message: body.show_message,
+        },
+        None => CustomError::Server {
+          status: status.as_u16(),
+          message: None,
+          show_message: None,
+        },
+      });
+    }
+
+    Ok(response)
+  }
+
+  async fn send_json<T: DeserializeOwned>(
+    &self,
+    request: RequestBuilder,
+  ) -> Result<T, CustomError> {
+    let response = self.send(request).await?;
+    let status = response.status();
+
+    let body = response.json::<CustomResponse<T>>().await?;
+
+    match body.data {
+      Some(data) => Ok(data),
+      None => Err(CustomError::Server {
+        status: status.as_u16(),
+        message: body.message.or(Some(String::from("Empty response"))),
+        show_message: body.show_message,
+      }),
+    }
+  }
+
+  pub async fn get<T: DeserializeOwned>(
+    &self,
+    endpoint: &str,
+    query: &[(&str, String)],
+  ) -> Result<T, CustomError> {
+    let request = self.request(Method::GET, endpoint)?.query(query);
+
+    self.send_json(request).await
+  }
+
+  pub async fn post<B: Serialize, T: DeserializeOwned>(
+    &self,
+    endpoint: &str,
+    body: &B,
+  ) -> Result<T, CustomError> {
+    let request = self.request(Method::POST, endpoint)?.json(body);
+
+    self.send_json(request).await
+  }
+
+  pub async fn put<B: Serialize, T: DeserializeOwned>(
+    &self,
+    endpoint: &str,
+    body: &B,
+  ) -> Result<T, CustomError> {
+    let request = self.request(Method::PUT, endpoint)?.json(body);
+
+    self.send_json(request).await
+  }
+
+  pub async fn delete(&self, endpoint: &str) -> Result<(), CustomError> {
+    let request = self.request(Method::DELETE, endpoint)?;
+
+    self.send(request).await?;
+
+    Ok(())
+  }
+
+  pub async fn status(&self, endpoint: &str) -> Result<StatusCode, CustomError> {
+    let response = self.request(Method::GET, endpoint)?.send().await?;
+
+    Ok(response.status())
+  }
+}
@@ -0,0 +1,31 @@
+use crate::{
+  models::{category::CategoryModel, custom_error::CustomError},
+  types::request::category::{CreateCategoryRequest, UpdateCategoryRequest},
+};
+
+use super::api_client::ApiClient;
+
+pub async fn get_categories(api: &ApiClient) -> Result<Vec<CategoryModel>, CustomError> {
+  api.get("/categories", &[]).await
+}
+
+pub async fn create_category(
+  api: &ApiClient,
+  new_category: CreateCategoryRequest,
+) -> Result<CategoryModel, CustomError> {
+  api.post("/categories", &new_category).await
+}
+
+pub async fn update_category(
+  api: &ApiClient,
+  category_id: &str,
+  category: UpdateCategoryRequest,
+) -> Result<CategoryModel, CustomError> {
+  api
+    .put(&format!("/categories/{}", category_id), &category)
+    .await
+}
+
+pub async fn delete_category(api: &ApiClient, category_id: &str) -> Result<(), CustomError> {
+  api.delete(&format!("/categories/{}", category_id)).await
+}
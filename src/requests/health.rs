@@ -1,13 +1,10 @@
-use reqwest::{StatusCode, Url};
+use reqwest::StatusCode;
+use crate::models::custom_error::CustomError;
 
-use super::get_url;
+use super::api_client::ApiClient;
 
-pub async fn request(client: &reqwest::Client) -> Result<bool, Box<dyn std::error::Error>> {
-  let url: Url = get_url("/health");
-
-  let response = client.get(url).send().await?;
-
-  let status: StatusCode = response.status();
+pub async fn request(api: &ApiClient) -> Result<bool, CustomError> {
+  let status: StatusCode = api.status("/health").await?;
 
   Ok(status == StatusCode::OK)
 }
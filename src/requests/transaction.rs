@@ -1,70 +1,22 @@
 use std::collections::HashMap;
-use std::error::Error;
-use reqwest::{
-  Url, StatusCode,
-  header::{HeaderValue, COOKIE, HeaderMap},
-};
 use crate::{
   models::{custom_error::CustomError, transaction::TransactionModel},
-  enums::{transaction_type::TransactionType, month::MonthEnum},
-  types::responses::{
-    response::CustomResponse,
-    transaction::{TransactionBalances, MonthByYear, GetTotalSaving},
+  enums::{currency::CurrencyEnum, transaction_type::TransactionType},
+  types::{
+    request::transaction::{CreateTransactionRequest, UpdateTransactionRequest},
+    responses::transaction::{TransactionBalances, TransactionTotal, MonthByYear, GetTotalSaving},
+    year_month::YearMonth,
   },
 };
 
-use super::get_url;
+use super::api_client::ApiClient;
 
 pub type MonthByYearMap = HashMap<String, Vec<String>>;
 
-pub async fn get_month_by_year(
-  client: &reqwest::Client,
-  session_id: &String,
-) -> Result<Vec<MonthByYear>, Box<dyn Error>> {
-  let url: Url = get_url("/transactions/month-by-years");
-
-  let cookie_header = HeaderValue::from_str(&format!("sessionID={}", session_id))?;
-  let mut header = HeaderMap::new();
-  header.insert(COOKIE, cookie_header);
-
-  let response = client.get(url).headers(header).send().await?;
-
-  if response.status() == StatusCode::INTERNAL_SERVER_ERROR {
-    return Err(Box::new(CustomError::new(
-      Some(""),
-      Some(String::from("Error to get months")),
-      None,
-    )));
-  }
-
-  if response.status() != StatusCode::OK {
-    let body = response.json::<CustomResponse<String>>().await?;
-
-    return Err(Box::new(CustomError::new(
-      if body.data.is_some() {
-        Some(body.data)
-      } else {
-        None
-      },
-      if body.message.is_some() {
-        Some(body.message.unwrap())
-      } else {
-        None
-      },
-      if body.show_message.is_some() {
-        Some(body.show_message.unwrap())
-      } else {
-        None
-      },
-    )));
-  }
-
-  let data: CustomResponse<MonthByYearMap> =
-    response.json::<CustomResponse<MonthByYearMap>>().await?;
+pub async fn get_month_by_year(api: &ApiClient) -> Result<Vec<MonthByYear>, CustomError> {
+  let data: MonthByYearMap = api.get("/transactions/month-by-years", &[]).await?;
 
   let month_by_year: Vec<MonthByYear> = data
-    .data
-    .unwrap()
     .into_iter()
     .map(|(year, months)| MonthByYear { year, months })
     .collect();
@@ -73,160 +25,61 @@ pub async fn get_month_by_year(
 }
 
 pub async fn get_transactions_by_month_and_type(
-  client: &reqwest::Client,
-  session_id: &String,
+  api: &ApiClient,
   transaction_type: TransactionType,
-  month: MonthEnum,
-) -> Result<Vec<TransactionModel>, Box<dyn Error>> {
-  let url: Url = get_url("/transactions");
-
+  period: &YearMonth,
+) -> Result<Vec<TransactionModel>, CustomError> {
   let query: Vec<(&str, String)> = vec![
     ("type", String::from(transaction_type)),
-    ("month", String::from(month)),
+    ("month", String::from(period.month.clone())),
+    ("year", period.year.to_string()),
   ];
 
-  let cookie_header = HeaderValue::from_str(&format!("sessionID={}", session_id))?;
-  let mut header = HeaderMap::new();
-  header.insert(COOKIE, cookie_header);
-
-  let response = client.get(url).headers(header).query(&query).send().await?;
-
-  if response.status() == StatusCode::INTERNAL_SERVER_ERROR {
-    return Err(Box::new(CustomError::new(
-      Some(""),
-      Some(String::from("Error to get transactions")),
-      None,
-    )));
-  }
-
-  if response.status() != StatusCode::OK {
-    let body = response.json::<CustomResponse<String>>().await?;
-
-    return Err(Box::new(CustomError::new(
-      if body.data.is_some() {
-        Some(body.data)
-      } else {
-        None
-      },
-      if body.message.is_some() {
-        Some(body.message.unwrap())
-      } else {
-        None
-      },
-      if body.show_message.is_some() {
-        Some(body.show_message.unwrap())
-      } else {
-        None
-      },
-    )));
-  }
-
-  let data: CustomResponse<Vec<TransactionModel>> = response
-    .json::<CustomResponse<Vec<TransactionModel>>>()
-    .await?;
-
-  Ok(data.data.unwrap().into_iter().collect())
+  api.get("/transactions", &query).await
 }
 
 pub async fn get_transactions_balances(
-  client: &reqwest::Client,
-  session_id: &String,
-  month: MonthEnum,
-) -> Result<TransactionBalances, Box<dyn Error>> {
-  let url: Url = get_url("/transactions");
-
+  api: &ApiClient,
+  period: &YearMonth,
+) -> Result<TransactionBalances, CustomError> {
   let query: Vec<(&str, String)> = vec![
     ("balance", String::from("true")),
-    ("month", String::from(month)),
+    ("month", String::from(period.month.clone())),
+    ("year", period.year.to_string()),
   ];
 
-  let cookie_header = HeaderValue::from_str(&format!("sessionID={}", session_id))?;
-  let mut header = HeaderMap::new();
-  header.insert(COOKIE, cookie_header);
-
-  let response = client.get(url).headers(header).query(&query).send().await?;
-
-  if response.status() == StatusCode::INTERNAL_SERVER_ERROR {
-    return Err(Box::new(CustomError::new(
-      Some(""),
-      Some(String::from("Error to get transactions")),
-      None,
-    )));
-  }
-
-  if response.status() != StatusCode::OK {
-    let body = response.json::<CustomResponse<String>>().await?;
-
-    return Err(Box::new(CustomError::new(
-      if body.data.is_some() {
-        Some(body.data)
-      } else {
-        None
-      },
-      if body.message.is_some() {
-        Some(body.message.unwrap())
-      } else {
-        None
-      },
-      if body.show_message.is_some() {
-        Some(body.show_message.unwrap())
-      } else {
-        None
-      },
-    )));
-  }
-
-  let data: CustomResponse<TransactionBalances> = response
-    .json::<CustomResponse<TransactionBalances>>()
-    .await?;
-
-  Ok(data.data.unwrap())
+  api.get("/transactions", &query).await
 }
 
-pub async fn get_total_saving(
-  client: &reqwest::Client,
-  session_id: &String,
-) -> Result<f64, Box<dyn Error>> {
-  let url: Url = get_url("/transactions/total-saving");
-
-  let cookie_header = HeaderValue::from_str(&format!("sessionID={}", session_id))?;
-  let mut header = HeaderMap::new();
-  header.insert(COOKIE, cookie_header);
-
-  let response = client.get(url).headers(header).send().await?;
+/// The server reports the accumulated savings in USD only.
+pub async fn get_total_saving(api: &ApiClient) -> Result<TransactionTotal, CustomError> {
+  let data: GetTotalSaving = api.get("/transactions/total-saving", &[]).await?;
 
-  if response.status() == StatusCode::INTERNAL_SERVER_ERROR {
-    return Err(Box::new(CustomError::new(
-      Some(""),
-      Some(String::from("Error to get transactions")),
-      None,
-    )));
-  }
+  let mut total = TransactionTotal::default();
+  total.add(&CurrencyEnum::USD, data.total_savings);
 
-  if response.status() != StatusCode::OK {
-    let body = response.json::<CustomResponse<String>>().await?;
+  Ok(total)
+}
 
-    return Err(Box::new(CustomError::new(
-      if body.data.is_some() {
-        Some(body.data)
-      } else {
-        None
-      },
-      if body.message.is_some() {
-        Some(body.message.unwrap())
-      } else {
-        None
-      },
-      if body.show_message.is_some() {
-        Some(body.show_message.unwrap())
-      } else {
-        None
-      },
-    )));
-  }
+pub async fn create_transaction(
+  api: &ApiClient,
+  new_transaction: CreateTransactionRequest,
+) -> Result<TransactionModel, CustomError> {
+  api.post("/transactions", &new_transaction).await
+}
 
-  let data: CustomResponse<GetTotalSaving> =
-    response.json::<CustomResponse<GetTotalSaving>>().await?;
+pub async fn update_transaction(
+  api: &ApiClient,
+  transaction_id: &str,
+  transaction: UpdateTransactionRequest,
+) -> Result<TransactionModel, CustomError> {
+  api
+    .put(&format!("/transactions/{}", transaction_id), &transaction)
+    .await
+}
 
-  Ok(data.data.unwrap().total_savings.round())
+pub async fn delete_transaction(api: &ApiClient, transaction_id: &str) -> Result<(), CustomError> {
+  api
+    .delete(&format!("/transactions/{}", transaction_id))
+    .await
 }
@@ -0,0 +1,40 @@
+use crossterm::event::KeyCode;
+use rust_decimal::Decimal;
+use tui_input::Input;
+
+use crate::{states::budgets::BudgetRow, utils::input::to_input_request};
+
+pub struct BudgetFormState {
+  pub category_id: Option<String>,
+  pub name: String,
+  pub limit: Input,
+  pub error: Option<String>,
+}
+
+impl BudgetFormState {
+  pub fn from_row(row: &BudgetRow) -> Self {
+    Self {
+      category_id: row.category_id.clone(),
+      name: row.name.clone(),
+      limit: Input::new(row.limit.map(|limit| limit.to_string()).unwrap_or_default()),
+      error: None,
+    }
+  }
+
+  /// An empty limit removes the budget.
+  pub fn to_limit(&self) -> Result<Option<Decimal>, String> {
+    match self.limit.value().trim() {
+      "" => Ok(None),
+      value => match value.parse::<Decimal>() {
+        Ok(limit) if limit > Decimal::ZERO => Ok(Some(limit)),
+        _ => Err(String::from("Limit must be a positive number")),
+      },
+    }
+  }
+
+  pub fn process_key_event(&mut self, key_code: KeyCode) {
+    if let Some(request) = to_input_request(key_code) {
+      self.limit.handle(request);
+    }
+  }
+}
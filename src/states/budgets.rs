@@ -0,0 +1,255 @@
+use rust_decimal::Decimal;
+
+use crate::{
+  enums::transaction_type::TransactionType,
+  models::{category::CategoryModel, custom_error::CustomError, transaction::TransactionModel},
+  utils::{budgets::Budgets, currency::Converter},
+};
+
+/// Spending of the month against a limit, `category_id` is `None` for the overall budget.
+pub struct BudgetRow {
+  pub category_id: Option<String>,
+  pub name: String,
+  pub spent: Decimal,
+  pub limit: Option<Decimal>,
+  /// Expenses left out of `spent` because they couldn't be converted.
+  pub missing: usize,
+}
+
+impl BudgetRow {
+  /// Fraction of the limit already spent, `None` without a limit.
+  pub fn ratio(&self) -> Option<Decimal> {
+    self
+      .limit
+      .filter(|limit| !limit.is_zero())
+      .map(|limit| self.spent / limit)
+  }
+
+  /// What is left of the limit, negative once it was exceeded.
+  pub fn remaining(&self) -> Option<Decimal> {
+    self.limit.map(|limit| limit - self.spent)
+  }
+
+  pub fn is_over(&self) -> bool {
+    self
+      .remaining()
+      .is_some_and(|remaining| remaining < Decimal::ZERO)
+  }
+}
+
+pub struct BudgetsState {
+  pub store: Budgets,
+  pub rows: Vec<BudgetRow>,
+  pub selected: usize,
+}
+
+impl BudgetsState {
+  pub fn new(store: Budgets) -> Self {
+    Self {
+      store,
+      rows: vec![],
+      selected: 0,
+    }
+  }
+
+  /// Rebuilds the rows from the month's expenses, one per expense category after the overall one.
+  pub fn update(
+    &mut self,
+    categories: &[CategoryModel],
+    expenses: &[TransactionModel],
+    converter: &Converter,
+  ) {
+    let currency = &self.store.data.currency;
+    let overall = converter.total_in(expenses, currency);
+
+    let mut rows = vec![BudgetRow {
+      category_id: None,
+      name: String::from("Overall"),
+      spent: overall.amount,
+      limit: self.store.limit(None),
+      missing: overall.missing,
+    }];
+
+    rows.extend(
+      categories
+        .iter()
+        .filter(|category| {
+          category.r#type == TransactionType::EXPENSE
+            || category.r#type == TransactionType::INSTALLMENTS
+        })
+        .map(|category| {
+          let spent = converter.total_in(
+            expenses
+              .iter()
+              .filter(|transaction| transaction.category_id == category.category_id),
+            currency,
+          );
+
+          BudgetRow {
+            category_id: Some(category.category_id.clone()),
+            name: category.name.clone(),
+            spent: spent.amount,
+            limit: self.store.limit(Some(&category.category_id)),
+            missing: spent.missing,
+          }
+        }),
+    );
+
+    self.rows = rows;
+    self.selected = self.selected.min(self.rows.len().saturating_sub(1));
+  }
+
+  pub fn next(&mut self) {
+    if !self.rows.is_empty() {
+      self.selected = (self.selected + 1) % self.rows.len();
+    }
+  }
+
+  pub fn previous(&mut self) {
+    if !self.rows.is_empty() {
+      self.selected = (self.selected + self.rows.len() - 1) % self.rows.len();
+    }
+  }
+
+  pub fn selected_row(&self) -> Option<&BudgetRow> {
+    self.rows.get(self.selected)
+  }
+
+  pub fn set_limit(
+    &mut self,
+    category_id: Option<&str>,
+    limit: Option<Decimal>,
+  ) -> Result<(), CustomError> {
+    self.store.set_limit(category_id, limit)?;
+
+    for row in self
+      .rows
+      .iter_mut()
+      .filter(|row| row.category_id.as_deref() == category_id)
+    {
+      row.limit = limit;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use crate::{enums::currency::CurrencyEnum, utils::fixtures::transaction};
+
+  use super::*;
+
+  fn category(category_id: &str, r#type: TransactionType) -> CategoryModel {
+    CategoryModel {
+      category_id: String::from(category_id),
+      r#type,
+      name: category_id.to_uppercase(),
+      note: None,
+      user_id: String::new(),
+      user: None,
+    }
+  }
+
+  fn budgets() -> BudgetsState {
+    let mut store = Budgets::disabled();
+    store.data.overall = Some(Decimal::from(1000));
+    store
+      .data
+      .categories
+      .insert(String::from("food"), Decimal::from(300));
+    store
+      .data
+      .categories
+      .insert(String::from("rent"), Decimal::from(500));
+
+    let categories = [
+      category("food", TransactionType::EXPENSE),
+      category("rent", TransactionType::EXPENSE),
+      category("fun", TransactionType::EXPENSE),
+      category("salary", TransactionType::INCOME),
+    ];
+    let expenses = [
+      transaction(TransactionType::EXPENSE, 200)
+        .category("food")
+        .build(),
+      transaction(TransactionType::EXPENSE, 5)
+        .currency(CurrencyEnum::USD)
+        .category("food")
+        .build(),
+      transaction(TransactionType::EXPENSE, 450)
+        .category("rent")
+        .build(),
+      transaction(TransactionType::EXPENSE, 10)
+        .currency(CurrencyEnum::EUR)
+        .category("fun")
+        .build(),
+    ];
+    let converter = Converter::new(
+      CurrencyEnum::UYU,
+      HashMap::from([(CurrencyEnum::USD, Decimal::from(40))]),
+    );
+
+    let mut budgets = BudgetsState::new(store);
+    budgets.update(&categories, &expenses, &converter);
+    budgets
+  }
+
+  #[test]
+  fn rows_sum_the_converted_expenses_of_each_category() {
+    let budgets = budgets();
+    let rows: Vec<(&str, Decimal, usize)> = budgets
+      .rows
+      .iter()
+      .map(|row| (row.name.as_str(), row.spent, row.missing))
+      .collect();
+
+    assert_eq!(
+      rows,
+      vec![
+        ("Overall", Decimal::from(850), 1),
+        ("FOOD", Decimal::from(400), 0),
+        ("RENT", Decimal::from(450), 0),
+        ("FUN", Decimal::ZERO, 1),
+      ]
+    );
+  }
+
+  #[test]
+  fn remaining_is_negative_once_over_budget() {
+    let budgets = budgets();
+    let [overall, food, rent, fun] = &budgets.rows[..] else {
+      panic!("expected four rows");
+    };
+
+    assert_eq!(overall.remaining(), Some(Decimal::from(150)));
+    assert!(!overall.is_over());
+
+    assert_eq!(food.remaining(), Some(Decimal::from(-100)));
+    assert!(food.is_over());
+    assert!(food.ratio().unwrap() > Decimal::ONE);
+
+    assert_eq!(rent.remaining(), Some(Decimal::from(50)));
+    assert_eq!(rent.ratio(), Some(Decimal::from_str_exact("0.9").unwrap()));
+
+    assert_eq!(fun.remaining(), None);
+    assert_eq!(fun.ratio(), None);
+    assert!(!fun.is_over());
+  }
+
+  #[test]
+  fn set_limit_updates_the_matching_row() {
+    let mut budgets = budgets();
+
+    budgets
+      .set_limit(Some("fun"), Some(Decimal::from(50)))
+      .unwrap();
+    assert_eq!(budgets.rows[3].remaining(), Some(Decimal::from(50)));
+
+    budgets.set_limit(None, None).unwrap();
+    assert_eq!(budgets.rows[0].limit, None);
+    assert_eq!(budgets.store.limit(None), None);
+  }
+}
@@ -0,0 +1,124 @@
+use crossterm::event::KeyCode;
+use tui_input::Input;
+
+use crate::{
+  enums::{category_form_field::CategoryFormField, transaction_type::TransactionType},
+  models::category::CategoryModel,
+  types::request::category::CreateCategoryRequest,
+  utils::input::to_input_request,
+};
+
+pub struct CategoryFormState {
+  pub category_id: Option<String>,
+  pub name: Input,
+  pub transaction_type: TransactionType,
+  pub note: Input,
+  pub selected_field: CategoryFormField,
+  pub error: Option<String>,
+  /// Set while the request is in flight, further submits are ignored.
+  pub saving: bool,
+}
+
+impl CategoryFormState {
+  pub fn new() -> Self {
+    Self {
+      category_id: None,
+      name: Input::default(),
+      transaction_type: TransactionType::EXPENSE,
+      note: Input::default(),
+      selected_field: CategoryFormField::Name,
+      error: None,
+      saving: false,
+    }
+  }
+
+  pub fn from_category(category: &CategoryModel) -> Self {
+    Self {
+      category_id: Some(category.category_id.clone()),
+      name: Input::new(category.name.clone()),
+      transaction_type: category.r#type.clone(),
+      note: Input::new(category.note.clone().unwrap_or_default()),
+      selected_field: CategoryFormField::Name,
+      error: None,
+      saving: false,
+    }
+  }
+
+  pub fn selected_input(&self) -> Option<&Input> {
+    match self.selected_field {
+      CategoryFormField::Name => Some(&self.name),
+      CategoryFormField::Note => Some(&self.note),
+      CategoryFormField::Type => None,
+    }
+  }
+
+  fn selected_input_mut(&mut self) -> Option<&mut Input> {
+    match self.selected_field {
+      CategoryFormField::Name => Some(&mut self.name),
+      CategoryFormField::Note => Some(&mut self.note),
+      CategoryFormField::Type => None,
+    }
+  }
+
+  fn select_next_field(&mut self) {
+    self.selected_field = match self.selected_field {
+      CategoryFormField::Name => CategoryFormField::Type,
+      CategoryFormField::Type => CategoryFormField::Note,
+      CategoryFormField::Note => CategoryFormField::Name,
+    };
+  }
+
+  fn select_previous_field(&mut self) {
+    self.selected_field = match self.selected_field {
+      CategoryFormField::Name => CategoryFormField::Note,
+      CategoryFormField::Type => CategoryFormField::Name,
+      CategoryFormField::Note => CategoryFormField::Type,
+    };
+  }
+
+  pub fn to_request(&self) -> Result<CreateCategoryRequest, String> {
+    let name = self.name.value().trim();
+
+    if name.is_empty() {
+      return Err(String::from("Name is required"));
+    }
+
+    Ok(CreateCategoryRequest {
+      name: name.to_string(),
+      r#type: self.transaction_type.clone(),
+      note: match self.note.value().trim() {
+        "" => None,
+        note => Some(note.to_string()),
+      },
+    })
+  }
+
+  pub fn process_key_event(&mut self, key_code: KeyCode) {
+    match key_code {
+      KeyCode::Tab | KeyCode::Down => self.select_next_field(),
+      KeyCode::BackTab | KeyCode::Up => self.select_previous_field(),
+      _ => match self.selected_input_mut() {
+        Some(input) => {
+          if let Some(request) = to_input_request(key_code) {
+            input.handle(request);
+          }
+        }
+        None => match key_code {
+          KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => {
+            self.transaction_type = self.transaction_type.next()
+          }
+          KeyCode::Left | KeyCode::Char('h') => {
+            self.transaction_type = self.transaction_type.previous()
+          }
+          _ => (),
+        },
+      },
+    }
+  }
+}
+
+impl Default for CategoryFormState {
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -0,0 +1,65 @@
+use crate::{
+  enums::month::MonthEnum,
+  types::{
+    responses::transaction::{MonthByYear, TransactionBalances},
+    year_month::YearMonth,
+  },
+};
+
+/// Balances of every month plotted on the Charts tab.
+pub struct ChartsState {
+  pub trends: Vec<(YearMonth, TransactionBalances)>,
+  pub loading: bool,
+  /// Set when the trends may be outdated and must be loaded again.
+  pub stale: bool,
+}
+
+impl Default for ChartsState {
+  fn default() -> Self {
+    Self {
+      trends: Vec::new(),
+      loading: false,
+      stale: true,
+    }
+  }
+}
+
+impl ChartsState {
+  pub fn set_trends(&mut self, mut trends: Vec<(YearMonth, TransactionBalances)>) {
+    trends.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    self.trends = trends;
+    self.loading = false;
+    self.stale = false;
+  }
+
+  /// Keeps an already loaded month in sync with the one shown in the tables.
+  pub fn update_month(&mut self, period: &YearMonth, summary: &TransactionBalances) {
+    if let Some((_, balances)) = self.trends.iter_mut().find(|(month, _)| month == period) {
+      *balances = summary.clone();
+    }
+  }
+}
+
+/// Every month listed in the months tree, oldest first.
+pub fn periods(months_by_year: &[MonthByYear]) -> Vec<YearMonth> {
+  let mut periods: Vec<YearMonth> = months_by_year
+    .iter()
+    .filter_map(|item| {
+      item
+        .year
+        .parse::<i32>()
+        .ok()
+        .map(|year| (year, &item.months))
+    })
+    .flat_map(|(year, months)| {
+      months.iter().filter_map(move |month| {
+        MonthEnum::from_string(month.clone()).map(|m| YearMonth::new(year, m))
+      })
+    })
+    .collect();
+
+  periods.sort();
+  periods.dedup();
+  periods
+}
@@ -0,0 +1,75 @@
+use std::env;
+
+use crossterm::event::KeyCode;
+use tui_input::Input;
+
+use crate::{
+  enums::{export_field::ExportField, export_format::ExportFormat},
+  types::year_month::YearMonth,
+  utils::input::to_input_request,
+};
+
+pub struct ExportState {
+  pub period: YearMonth,
+  pub format: ExportFormat,
+  pub path: Input,
+  pub selected_field: ExportField,
+  pub error: Option<String>,
+}
+
+fn default_path(period: &YearMonth, format: &ExportFormat) -> String {
+  format!(
+    "{}/expenses-{}.{}",
+    env::var("HOME").unwrap_or_else(|_| String::from(".")),
+    period,
+    format.extension()
+  )
+}
+
+impl ExportState {
+  pub fn new(period: YearMonth) -> Self {
+    let format = ExportFormat::Csv;
+
+    Self {
+      path: Input::new(default_path(&period, &format)),
+      period,
+      format,
+      selected_field: ExportField::Format,
+      error: None,
+    }
+  }
+
+  /// Changes the format, keeping the suggested file name in sync unless it was edited.
+  fn set_format(&mut self, format: ExportFormat) {
+    if self.path.value() == default_path(&self.period, &self.format) {
+      self.path = Input::new(default_path(&self.period, &format));
+    }
+
+    self.format = format;
+  }
+
+  pub fn process_key_event(&mut self, key_code: KeyCode) {
+    match key_code {
+      KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => {
+        self.selected_field = match self.selected_field {
+          ExportField::Format => ExportField::Path,
+          ExportField::Path => ExportField::Format,
+        }
+      }
+      _ => match self.selected_field {
+        ExportField::Format => match key_code {
+          KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => {
+            self.set_format(self.format.next())
+          }
+          KeyCode::Left | KeyCode::Char('h') => self.set_format(self.format.previous()),
+          _ => (),
+        },
+        ExportField::Path => {
+          if let Some(request) = to_input_request(key_code) {
+            self.path.handle(request);
+          }
+        }
+      },
+    }
+  }
+}
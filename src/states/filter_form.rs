@@ -0,0 +1,154 @@
+use crossterm::event::KeyCode;
+use rust_decimal::Decimal;
+use tui_input::Input;
+
+use crate::{
+  enums::{currency::CurrencyEnum, filter_field::FilterField},
+  utils::{
+    filter::{parse_days, TransactionFilter},
+    input::to_input_request,
+  },
+};
+
+pub struct FilterFormState {
+  pub text: Input,
+  pub category: Input,
+  pub min: Input,
+  pub max: Input,
+  pub currency: Option<CurrencyEnum>,
+  pub days: Input,
+  pub selected_field: FilterField,
+  pub error: Option<String>,
+}
+
+fn parse_amount(value: &str, name: &str) -> Result<Option<Decimal>, String> {
+  match value.trim() {
+    "" => Ok(None),
+    value => match value.parse::<Decimal>() {
+      Ok(amount) => Ok(Some(amount)),
+      Err(_) => Err(format!("{} must be a number", name)),
+    },
+  }
+}
+
+impl FilterFormState {
+  pub fn new(filter: &TransactionFilter) -> Self {
+    let amount = |amount: Option<Decimal>| match amount {
+      Some(amount) => Input::new(amount.to_string()),
+      None => Input::default(),
+    };
+
+    Self {
+      text: Input::new(filter.text.clone()),
+      category: Input::new(filter.category.clone()),
+      min: amount(filter.min),
+      max: amount(filter.max),
+      currency: filter.currency.clone(),
+      days: match filter.days {
+        Some((from, to)) if from == to => Input::new(from.to_string()),
+        Some((from, to)) => Input::new(format!("{}-{}", from, to)),
+        None => Input::default(),
+      },
+      selected_field: FilterField::Text,
+      error: None,
+    }
+  }
+
+  pub fn selected_input(&self) -> Option<&Input> {
+    match self.selected_field {
+      FilterField::Text => Some(&self.text),
+      FilterField::Category => Some(&self.category),
+      FilterField::Min => Some(&self.min),
+      FilterField::Max => Some(&self.max),
+      FilterField::Days => Some(&self.days),
+      FilterField::Currency => None,
+    }
+  }
+
+  fn selected_input_mut(&mut self) -> Option<&mut Input> {
+    match self.selected_field {
+      FilterField::Text => Some(&mut self.text),
+      FilterField::Category => Some(&mut self.category),
+      FilterField::Min => Some(&mut self.min),
+      FilterField::Max => Some(&mut self.max),
+      FilterField::Days => Some(&mut self.days),
+      FilterField::Currency => None,
+    }
+  }
+
+  fn select_next_field(&mut self) {
+    self.selected_field = match self.selected_field {
+      FilterField::Text => FilterField::Category,
+      FilterField::Category => FilterField::Min,
+      FilterField::Min => FilterField::Max,
+      FilterField::Max => FilterField::Currency,
+      FilterField::Currency => FilterField::Days,
+      FilterField::Days => FilterField::Text,
+    };
+  }
+
+  fn select_previous_field(&mut self) {
+    self.selected_field = match self.selected_field {
+      FilterField::Text => FilterField::Days,
+      FilterField::Category => FilterField::Text,
+      FilterField::Min => FilterField::Category,
+      FilterField::Max => FilterField::Min,
+      FilterField::Currency => FilterField::Max,
+      FilterField::Days => FilterField::Currency,
+    };
+  }
+
+  /// Cycles through the known currencies with "any" between the last and the first.
+  fn select_currency(&mut self, forward: bool) {
+    let known = &CurrencyEnum::KNOWN;
+
+    self.currency = match &self.currency {
+      None if forward => known.first().cloned(),
+      None => known.last().cloned(),
+      Some(currency) => match known.iter().position(|known| known == currency) {
+        Some(i) if forward => known.get(i + 1).cloned(),
+        Some(i) if i > 0 => known.get(i - 1).cloned(),
+        _ => None,
+      },
+    };
+  }
+
+  pub fn to_filter(&self) -> Result<TransactionFilter, String> {
+    let min = parse_amount(self.min.value(), "Min")?;
+    let max = parse_amount(self.max.value(), "Max")?;
+
+    if let (Some(min), Some(max)) = (min, max) {
+      if min > max {
+        return Err(String::from("Min can't be greater than max"));
+      }
+    }
+
+    Ok(TransactionFilter {
+      text: self.text.value().trim().to_string(),
+      category: self.category.value().trim().to_string(),
+      min,
+      max,
+      currency: self.currency.clone(),
+      days: parse_days(self.days.value())?,
+    })
+  }
+
+  pub fn process_key_event(&mut self, key_code: KeyCode) {
+    match key_code {
+      KeyCode::Tab | KeyCode::Down => self.select_next_field(),
+      KeyCode::BackTab | KeyCode::Up => self.select_previous_field(),
+      _ => match self.selected_input_mut() {
+        Some(input) => {
+          if let Some(request) = to_input_request(key_code) {
+            input.handle(request);
+          }
+        }
+        None => match key_code {
+          KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => self.select_currency(true),
+          KeyCode::Left | KeyCode::Char('h') => self.select_currency(false),
+          _ => (),
+        },
+      },
+    }
+  }
+}
@@ -0,0 +1,533 @@
+use std::collections::HashSet;
+
+use chrono::{Datelike, NaiveDate};
+use crossterm::event::KeyCode;
+use rust_decimal::Decimal;
+use tui_input::Input;
+
+use crate::{
+  enums::{
+    currency::CurrencyEnum, currency_source::CurrencySource, import_field::ImportField,
+    import_step::ImportStep, month::MonthEnum, transaction_type::TransactionType,
+  },
+  models::transaction::TransactionModel,
+  types::{money::Money, request::transaction::CreateTransactionRequest},
+  utils::input::to_input_request,
+};
+
+use super::tables::CustomTableState;
+
+const DATE_FORMATS: [&str; 5] = ["%Y-%m-%d", "%d/%m/%Y", "%d-%m-%Y", "%d.%m.%Y", "%Y/%m/%d"];
+
+const DATE_HEADERS: [&str; 3] = ["date", "fecha", "day"];
+const AMOUNT_HEADERS: [&str; 5] = ["amount", "importe", "monto", "debit", "débito"];
+const CURRENCY_HEADERS: [&str; 2] = ["currency", "moneda"];
+const NOTE_HEADERS: [&str; 5] = ["note", "description", "descripción", "concepto", "detalle"];
+
+pub struct ImportRow {
+  pub result: Result<CreateTransactionRequest, String>,
+  pub duplicate: bool,
+  pub included: bool,
+}
+
+pub struct ImportState {
+  pub path: Input,
+  pub step: ImportStep,
+  pub headers: Vec<String>,
+  pub records: Vec<Vec<String>>,
+  pub date_column: Option<usize>,
+  pub amount_column: Option<usize>,
+  pub currency: CurrencySource,
+  pub note_column: Option<usize>,
+  pub transaction_type: TransactionType,
+  pub selected_field: ImportField,
+  pub rows: Vec<ImportRow>,
+  pub table: CustomTableState,
+  pub error: Option<String>,
+}
+
+/// Identifies a transaction for duplicate detection: same date, amount and currency.
+type Fingerprint = (u16, MonthEnum, Option<u8>, Decimal, String);
+
+fn fingerprint(
+  year: u16,
+  month: &MonthEnum,
+  day: Option<u8>,
+  amount: Decimal,
+  currency: &CurrencyEnum,
+) -> Fingerprint {
+  (
+    year,
+    month.clone(),
+    day,
+    amount.abs().round_dp(2).normalize(),
+    String::from(currency.clone()),
+  )
+}
+
+fn guess_column(headers: &[String], names: &[&str]) -> Option<usize> {
+  headers.iter().position(|header| {
+    let header = header.trim().to_lowercase();
+    names.iter().any(|name| header.contains(name))
+  })
+}
+
+fn cycle_column(column: Option<usize>, len: usize, forward: bool) -> Option<usize> {
+  match (column, forward) {
+    _ if len == 0 => None,
+    (None, true) => Some(0),
+    (Some(i), true) if i + 1 < len => Some(i + 1),
+    (Some(_), true) => None,
+    (None, false) => Some(len - 1),
+    (Some(0), false) => None,
+    (Some(i), false) => Some(i - 1),
+  }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+  DATE_FORMATS
+    .iter()
+    .find_map(|format| NaiveDate::parse_from_str(value.trim(), format).ok())
+}
+
+/// Accepts both `1,234.56` and `1.234,56`. With both separators the last one is the
+/// decimal one, a lone separator is a thousands one when it repeats or is followed by
+/// exactly three digits, so `1,234` and `1.500` are read as whole amounts.
+fn parse_amount(value: &str) -> Option<Decimal> {
+  let cleaned: String = value
+    .chars()
+    .filter(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | '-'))
+    .collect();
+
+  let is_thousands = |separator: char| {
+    let mut groups = cleaned.split(separator);
+    let integer = groups.next().unwrap_or_default().trim_start_matches('-');
+    let groups: Vec<&str> = groups.collect();
+
+    !matches!(integer, "" | "0")
+      && (groups.len() > 1 || groups.iter().all(|group| group.len() == 3))
+  };
+
+  let normalized = match (cleaned.rfind('.'), cleaned.rfind(',')) {
+    (Some(dot), Some(comma)) if comma > dot => cleaned.replace('.', "").replace(',', "."),
+    (Some(_), Some(_)) => cleaned.replace(',', ""),
+    (None, Some(_)) if is_thousands(',') => cleaned.replace(',', ""),
+    (None, Some(_)) => cleaned.replace(',', "."),
+    (Some(_), None) if is_thousands('.') => cleaned.replace('.', ""),
+    _ => cleaned,
+  };
+
+  normalized.parse().ok()
+}
+
+impl ImportState {
+  pub fn new() -> Self {
+    Self {
+      path: Input::default(),
+      step: ImportStep::File,
+      headers: Vec::new(),
+      records: Vec::new(),
+      date_column: None,
+      amount_column: None,
+      currency: CurrencySource::Fixed(CurrencyEnum::UYU),
+      note_column: None,
+      transaction_type: TransactionType::EXPENSE,
+      selected_field: ImportField::Date,
+      rows: Vec::new(),
+      table: CustomTableState::new(Vec::new()),
+      error: None,
+    }
+  }
+
+  fn load_file(&mut self) -> Result<(), String> {
+    let path = self.path.value().trim();
+
+    if path.is_empty() {
+      return Err(String::from("Path is required"));
+    }
+
+    let mut reader = csv::ReaderBuilder::new()
+      .flexible(true)
+      .trim(csv::Trim::All)
+      .from_path(path)
+      .map_err(|err| format!("Unable to open {}: {}", path, err))?;
+
+    self.headers = reader
+      .headers()
+      .map_err(|err| err.to_string())?
+      .iter()
+      .map(String::from)
+      .collect();
+
+    self.records = reader
+      .records()
+      .map(|record| record.map(|record| record.iter().map(String::from).collect()))
+      .collect::<Result<_, _>>()
+      .map_err(|err| err.to_string())?;
+
+    if self.records.is_empty() {
+      return Err(String::from("The file has no rows"));
+    }
+
+    self.date_column = guess_column(&self.headers, &DATE_HEADERS);
+    self.amount_column = guess_column(&self.headers, &AMOUNT_HEADERS);
+    self.note_column = guess_column(&self.headers, &NOTE_HEADERS);
+    self.currency = match guess_column(&self.headers, &CURRENCY_HEADERS) {
+      Some(column) => CurrencySource::Column(column),
+      None => CurrencySource::Fixed(CurrencyEnum::UYU),
+    };
+
+    Ok(())
+  }
+
+  pub fn column_name(&self, column: Option<usize>) -> String {
+    column
+      .and_then(|column| self.headers.get(column))
+      .cloned()
+      .unwrap_or_else(|| String::from("-"))
+  }
+
+  pub fn currency_name(&self) -> String {
+    match &self.currency {
+      CurrencySource::Fixed(currency) => format!("Always {}", currency),
+      CurrencySource::Column(column) => self.column_name(Some(*column)),
+    }
+  }
+
+  /// Cycles fixed currencies first and then every column of the file.
+  fn cycle_currency(&mut self, forward: bool) {
+    let fixed = CurrencyEnum::KNOWN;
+    let len = fixed.len() + self.headers.len();
+
+    let index = match &self.currency {
+      CurrencySource::Fixed(currency) => fixed.iter().position(|c| c == currency).unwrap_or(0),
+      CurrencySource::Column(column) => fixed.len() + column,
+    };
+
+    let index = if forward {
+      (index + 1) % len
+    } else {
+      (index + len - 1) % len
+    };
+
+    self.currency = match fixed.get(index) {
+      Some(currency) => CurrencySource::Fixed(currency.clone()),
+      None => CurrencySource::Column(index - fixed.len()),
+    };
+  }
+
+  fn to_request(&self, record: &[String]) -> Result<CreateTransactionRequest, String> {
+    let cell = |column: Option<usize>| {
+      column
+        .and_then(|column| record.get(column))
+        .map(|value| value.as_str())
+        .unwrap_or("")
+    };
+
+    let date = parse_date(cell(self.date_column))
+      .ok_or_else(|| format!("Invalid date '{}'", cell(self.date_column)))?;
+
+    let amount = match parse_amount(cell(self.amount_column)) {
+      Some(amount) if !amount.is_zero() => amount,
+      _ => return Err(format!("Invalid amount '{}'", cell(self.amount_column))),
+    };
+
+    // Refunds and credits come as negative amounts, they go the other way.
+    let transaction_type = match (&self.transaction_type, amount.is_sign_negative()) {
+      (transaction_type, false) => transaction_type.clone(),
+      (TransactionType::EXPENSE, true) => TransactionType::INCOME,
+      (TransactionType::INCOME, true) => TransactionType::EXPENSE,
+      (transaction_type, true) => {
+        return Err(format!(
+          "Negative amount '{}' can't be imported as {}",
+          cell(self.amount_column),
+          String::from(transaction_type.clone()).to_lowercase()
+        ))
+      }
+    };
+
+    let currency = match &self.currency {
+      CurrencySource::Fixed(currency) => currency.clone(),
+      CurrencySource::Column(column) => {
+        let value = cell(Some(*column));
+        CurrencyEnum::from_string(value.to_string())
+          .ok_or_else(|| format!("Unknown currency '{}'", value))?
+      }
+    };
+
+    let note = match cell(self.note_column).trim() {
+      "" => None,
+      note => Some(note.to_string()),
+    };
+
+    Ok(CreateTransactionRequest {
+      r#type: transaction_type,
+      amount: amount.abs(),
+      currency,
+      exchange_rate: None,
+      day: Some(date.day() as u8),
+      month: MonthEnum::from_u32(date.month()).unwrap_or(MonthEnum::JANUARY),
+      year: date.year() as u16,
+      note,
+      category_id: None,
+    })
+  }
+
+  /// Parses every record and flags the ones already present in `existing` or
+  /// repeated earlier in the file. Duplicates start excluded from the import.
+  fn build_rows(&mut self, existing: &[TransactionModel]) {
+    let mut known: HashSet<Fingerprint> = existing
+      .iter()
+      .map(|t| fingerprint(t.year, &t.month, t.day, t.amount, &t.currency))
+      .collect();
+
+    self.rows = self
+      .records
+      .iter()
+      .map(|record| {
+        let result = self.to_request(record);
+
+        let duplicate = match &result {
+          Ok(r) => !known.insert(fingerprint(r.year, &r.month, r.day, r.amount, &r.currency)),
+          Err(_) => false,
+        };
+
+        ImportRow {
+          included: result.is_ok() && !duplicate,
+          result,
+          duplicate,
+        }
+      })
+      .collect();
+
+    self.update_table();
+    self.table.state.select(Some(0));
+  }
+
+  fn update_table(&mut self) {
+    self.table.items = self
+      .rows
+      .iter()
+      .map(|row| {
+        let included = String::from(if row.included { "[x]" } else { "[ ]" });
+
+        match &row.result {
+          Ok(request) => vec![
+            included,
+            format!(
+              "{:04}-{:02}-{:02}",
+              request.year,
+              request.month.to_u32(),
+              request.day.unwrap_or(1)
+            ),
+            // Negative amounts were imported with the opposite type, keep the sign visible.
+            match request.r#type == self.transaction_type {
+              true => Money::new(request.amount, request.currency.clone()).to_string(),
+              false => format!("-{}", Money::new(request.amount, request.currency.clone())),
+            },
+            String::from(if row.duplicate { "Duplicate" } else { "New" }),
+            request.note.clone().unwrap_or_default(),
+          ],
+          Err(err) => vec![
+            included,
+            String::new(),
+            String::new(),
+            String::from("Error"),
+            err.clone(),
+          ],
+        }
+      })
+      .collect();
+  }
+
+  fn toggle_selected(&mut self) {
+    if let Some(row) = self
+      .table
+      .state
+      .selected()
+      .and_then(|index| self.rows.get_mut(index))
+    {
+      row.included = row.result.is_ok() && !row.included;
+      self.update_table();
+    }
+  }
+
+  pub fn selected_requests(&self) -> Vec<CreateTransactionRequest> {
+    self
+      .rows
+      .iter()
+      .filter(|row| row.included)
+      .filter_map(|row| row.result.clone().ok())
+      .collect()
+  }
+
+  /// Counts of rows that are new, duplicated and unparseable.
+  pub fn summary(&self) -> (usize, usize, usize) {
+    self
+      .rows
+      .iter()
+      .fold((0, 0, 0), |(new, duplicates, errors), row| match row {
+        ImportRow { result: Err(_), .. } => (new, duplicates, errors + 1),
+        ImportRow {
+          duplicate: true, ..
+        } => (new, duplicates + 1, errors),
+        _ => (new + 1, duplicates, errors),
+      })
+  }
+
+  /// Goes back one step, returns false when already on the first one.
+  pub fn back(&mut self) -> bool {
+    self.error = None;
+    self.step = match self.step {
+      ImportStep::File => return false,
+      ImportStep::Mapping => ImportStep::File,
+      ImportStep::Preview => ImportStep::Mapping,
+    };
+
+    true
+  }
+
+  fn select_next_field(&mut self) {
+    self.selected_field = match self.selected_field {
+      ImportField::Date => ImportField::Amount,
+      ImportField::Amount => ImportField::Currency,
+      ImportField::Currency => ImportField::Note,
+      ImportField::Note => ImportField::Type,
+      ImportField::Type => ImportField::Date,
+    };
+  }
+
+  fn select_previous_field(&mut self) {
+    self.selected_field = match self.selected_field {
+      ImportField::Date => ImportField::Type,
+      ImportField::Amount => ImportField::Date,
+      ImportField::Currency => ImportField::Amount,
+      ImportField::Note => ImportField::Currency,
+      ImportField::Type => ImportField::Note,
+    };
+  }
+
+  fn cycle_option(&mut self, forward: bool) {
+    let len = self.headers.len();
+
+    match self.selected_field {
+      ImportField::Date => self.date_column = cycle_column(self.date_column, len, forward),
+      ImportField::Amount => self.amount_column = cycle_column(self.amount_column, len, forward),
+      ImportField::Note => self.note_column = cycle_column(self.note_column, len, forward),
+      ImportField::Currency => self.cycle_currency(forward),
+      ImportField::Type => {
+        self.transaction_type = if forward {
+          self.transaction_type.next()
+        } else {
+          self.transaction_type.previous()
+        }
+      }
+    }
+  }
+
+  pub fn process_key_event(&mut self, key_code: KeyCode, existing: &[TransactionModel]) {
+    match self.step {
+      ImportStep::File => match key_code {
+        KeyCode::Enter => match self.load_file() {
+          Ok(_) => {
+            self.error = None;
+            self.step = ImportStep::Mapping;
+          }
+          Err(err) => self.error = Some(err),
+        },
+        _ => {
+          if let Some(request) = to_input_request(key_code) {
+            self.path.handle(request);
+          }
+        }
+      },
+      ImportStep::Mapping => match key_code {
+        KeyCode::Tab | KeyCode::Down => self.select_next_field(),
+        KeyCode::BackTab | KeyCode::Up => self.select_previous_field(),
+        KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => self.cycle_option(true),
+        KeyCode::Left | KeyCode::Char('h') => self.cycle_option(false),
+        KeyCode::Enter => {
+          if self.date_column.is_none() || self.amount_column.is_none() {
+            self.error = Some(String::from("Date and amount columns are required"));
+          } else {
+            self.error = None;
+            self.build_rows(existing);
+            self.step = ImportStep::Preview;
+          }
+        }
+        _ => (),
+      },
+      ImportStep::Preview => match key_code {
+        KeyCode::Down | KeyCode::Char('j') => self.table.next(),
+        KeyCode::Up | KeyCode::Char('k') => self.table.previous(),
+        KeyCode::Char(' ') => self.toggle_selected(),
+        _ => (),
+      },
+    }
+  }
+}
+
+impl Default for ImportState {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::str::FromStr;
+
+  use super::*;
+
+  fn amount(value: &str) -> Option<Decimal> {
+    Some(Decimal::from_str(value).unwrap())
+  }
+
+  #[test]
+  fn parse_amount_reads_the_last_of_two_separators_as_decimal() {
+    assert_eq!(parse_amount("1,234.56"), amount("1234.56"));
+    assert_eq!(parse_amount("1.234,56"), amount("1234.56"));
+    assert_eq!(parse_amount("$ 1.234.567,8"), amount("1234567.8"));
+  }
+
+  #[test]
+  fn parse_amount_reads_a_three_digit_group_as_thousands() {
+    assert_eq!(parse_amount("1,234"), amount("1234"));
+    assert_eq!(parse_amount("1.500"), amount("1500"));
+    assert_eq!(parse_amount("-2.000"), amount("-2000"));
+    assert_eq!(parse_amount("1,234,567"), amount("1234567"));
+  }
+
+  #[test]
+  fn parse_amount_reads_other_lone_separators_as_decimal() {
+    assert_eq!(parse_amount("12,5"), amount("12.5"));
+    assert_eq!(parse_amount("12.50"), amount("12.50"));
+    assert_eq!(parse_amount("0,500"), amount("0.500"));
+    assert_eq!(parse_amount("-45.5"), amount("-45.5"));
+  }
+
+  #[test]
+  fn parse_amount_rejects_text() {
+    assert_eq!(parse_amount(""), None);
+    assert_eq!(parse_amount("n/a"), None);
+  }
+
+  #[test]
+  fn negative_amounts_take_the_opposite_type() {
+    let mut import = ImportState::new();
+    import.date_column = Some(0);
+    import.amount_column = Some(1);
+
+    let record = |amount: &str| vec![String::from("2023-03-05"), String::from(amount)];
+
+    let request = import.to_request(&record("-1.500")).unwrap();
+    assert_eq!(request.r#type, TransactionType::INCOME);
+    assert_eq!(request.amount, Decimal::from(1500));
+
+    let request = import.to_request(&record("20")).unwrap();
+    assert_eq!(request.r#type, TransactionType::EXPENSE);
+
+    import.transaction_type = TransactionType::SAVING;
+    assert!(import.to_request(&record("-20")).is_err());
+  }
+}
@@ -0,0 +1,62 @@
+use tui::widgets::TableState;
+
+use crate::{
+  models::transaction::TransactionModel,
+  utils::installments::{self, InstallmentPlan},
+};
+
+/// Installment plans of every month shown on the Installments tab.
+pub struct InstallmentsState {
+  pub plans: Vec<InstallmentPlan>,
+  pub table: TableState,
+  pub loading: bool,
+  /// Set when the plans may be outdated and must be loaded again.
+  pub stale: bool,
+}
+
+impl Default for InstallmentsState {
+  fn default() -> Self {
+    Self {
+      plans: Vec::new(),
+      table: TableState::default(),
+      loading: false,
+      stale: true,
+    }
+  }
+}
+
+impl InstallmentsState {
+  pub fn set_transactions(&mut self, transactions: &[TransactionModel]) {
+    self.plans = installments::plans(transactions);
+    self.loading = false;
+    self.stale = false;
+
+    match self.table.selected() {
+      Some(_) if self.plans.is_empty() => self.table.select(None),
+      Some(i) if i >= self.plans.len() => self.table.select(Some(self.plans.len() - 1)),
+      _ => (),
+    }
+  }
+
+  pub fn next(&mut self) {
+    if self.plans.is_empty() {
+      return;
+    }
+
+    let i = self
+      .table
+      .selected()
+      .map_or(0, |i| (i + 1) % self.plans.len());
+    self.table.select(Some(i));
+  }
+
+  pub fn previous(&mut self) {
+    if self.plans.is_empty() {
+      return;
+    }
+
+    let len = self.plans.len();
+    let i = self.table.selected().map_or(0, |i| (i + len - 1) % len);
+    self.table.select(Some(i));
+  }
+}
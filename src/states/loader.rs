@@ -0,0 +1,396 @@
+use std::{future::Future, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use tokio::{
+  sync::{mpsc::UnboundedSender, Semaphore},
+  task::JoinHandle,
+};
+
+use crate::{
+  enums::{
+    delete_target::DeleteTarget, loader_message::LoaderMessage,
+    pending_operation::PendingOperation, transaction_type::TransactionType,
+  },
+  models::{custom_error::CustomError, transaction::TransactionModel},
+  storage::{SharedStorage, Storage},
+  types::{
+    request::{category::CreateCategoryRequest, transaction::CreateTransactionRequest},
+    responses::transaction::{TransactionBalances, TransactionTotal},
+    year_month::YearMonth,
+  },
+  ui::get_transactions,
+  utils::transaction_utils,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthData {
+  pub expenses: Vec<TransactionModel>,
+  pub incomes: Vec<TransactionModel>,
+  pub savings: Vec<TransactionModel>,
+  pub summary: TransactionBalances,
+  pub total_saving: TransactionTotal,
+}
+
+pub async fn fetch_month(
+  storage: &dyn Storage,
+  period: &YearMonth,
+) -> Result<MonthData, CustomError> {
+  let (transactions, summary, total_saving) = tokio::join!(
+    get_transactions(storage, period),
+    storage.get_transactions_balances(period),
+    storage.get_total_saving(),
+  );
+
+  let (mut expenses, mut incomes, mut savings) = transactions?;
+
+  transaction_utils::sort(&mut expenses);
+  transaction_utils::sort(&mut incomes);
+  transaction_utils::sort(&mut savings);
+
+  Ok(MonthData {
+    expenses,
+    incomes,
+    savings,
+    summary: summary?,
+    total_saving: total_saving?,
+  })
+}
+
+/// Months fetched at the same time by `fetch_months`.
+const MONTH_REQUESTS: usize = 4;
+
+type MonthResults<T> = Vec<(YearMonth, Result<T, CustomError>)>;
+
+/// Runs `fetch` for every month, `MONTH_REQUESTS` at a time. Results keep the
+/// given order and a failed month doesn't stop the others.
+async fn fetch_months<T, F, Fut>(
+  storage: &SharedStorage,
+  periods: Vec<YearMonth>,
+  fetch: F,
+) -> MonthResults<T>
+where
+  T: Send + 'static,
+  F: Fn(SharedStorage, YearMonth) -> Fut,
+  Fut: Future<Output = Result<T, CustomError>> + Send + 'static,
+{
+  let permits = Arc::new(Semaphore::new(MONTH_REQUESTS));
+
+  let handles: Vec<_> = periods
+    .into_iter()
+    .map(|period| {
+      let request = fetch(storage.clone(), period.clone());
+      let permits = permits.clone();
+
+      let handle = tokio::spawn(async move {
+        let _permit = permits.acquire_owned().await;
+        request.await
+      });
+      (period, handle)
+    })
+    .collect();
+
+  let mut results = Vec::new();
+
+  for (period, handle) in handles {
+    let result = handle
+      .await
+      .map_err(CustomError::from)
+      .and_then(|result| result);
+    results.push((period, result));
+  }
+
+  results
+}
+
+/// Every month's value, or the first month's error.
+fn all_months<T>(results: MonthResults<T>) -> Result<Vec<(YearMonth, T)>, CustomError> {
+  results
+    .into_iter()
+    .map(|(period, result)| result.map(|value| (period, value)))
+    .collect()
+}
+
+async fn fetch_balances(
+  storage: &SharedStorage,
+  periods: Vec<YearMonth>,
+) -> MonthResults<TransactionBalances> {
+  fetch_months(storage, periods, |storage, period| async move {
+    storage.get_transactions_balances(&period).await
+  })
+  .await
+}
+
+/// Creates the transactions one by one, stopping at the first failure.
+async fn create_all(
+  storage: &dyn Storage,
+  requests: Vec<CreateTransactionRequest>,
+) -> (usize, Option<CustomError>) {
+  let mut created = 0;
+
+  for request in requests {
+    if let Err(err) = storage.create_transaction(request).await {
+      return (created, Some(err));
+    }
+
+    created += 1;
+  }
+
+  (created, None)
+}
+
+/// Runs requests on background tasks and sends the results to the run loop.
+pub struct Loader {
+  sender: UnboundedSender<LoaderMessage>,
+  request_id: u64,
+  in_flight: Option<JoinHandle<()>>,
+}
+
+impl Loader {
+  pub fn new(sender: UnboundedSender<LoaderMessage>) -> Self {
+    Self {
+      sender,
+      request_id: 0,
+      in_flight: None,
+    }
+  }
+
+  /// Starts loading a month, aborting the previous load if it is still running.
+  pub fn load_month(&mut self, storage: &SharedStorage, period: YearMonth) {
+    if let Some(handle) = self.in_flight.take() {
+      handle.abort();
+    }
+
+    self.request_id += 1;
+
+    let request_id = self.request_id;
+    let sender = self.sender.clone();
+    let storage = storage.clone();
+
+    self.in_flight = Some(tokio::spawn(async move {
+      let result = fetch_month(storage.as_ref(), &period).await;
+      let _ = sender.send(LoaderMessage::Month {
+        request_id,
+        period,
+        result,
+      });
+    }));
+  }
+
+  pub fn load_categories(&self, storage: &SharedStorage) {
+    let sender = self.sender.clone();
+    let storage = storage.clone();
+
+    tokio::spawn(async move {
+      let result = storage.get_categories().await;
+      let _ = sender.send(LoaderMessage::Categories(result));
+    });
+  }
+
+  /// Loads the balances of the given months for the Charts tab.
+  pub fn load_trends(&self, storage: &SharedStorage, periods: Vec<YearMonth>) {
+    let sender = self.sender.clone();
+    let storage = storage.clone();
+
+    tokio::spawn(async move {
+      let result = all_months(fetch_balances(&storage, periods).await);
+      let _ = sender.send(LoaderMessage::Trends(result));
+    });
+  }
+
+  /// Loads the balances of the twelve months of a year, keeping the months
+  /// that loaded when others fail.
+  pub fn load_year(&self, storage: &SharedStorage, year: i32) {
+    let sender = self.sender.clone();
+    let storage = storage.clone();
+
+    tokio::spawn(async move {
+      let mut balances = Vec::new();
+      let mut error = None;
+
+      for (period, result) in fetch_balances(&storage, YearMonth::months_of(year)).await {
+        match result {
+          Ok(summary) => balances.push((period, summary)),
+          Err(err) => {
+            error.get_or_insert(err);
+          }
+        }
+      }
+
+      let _ = sender.send(LoaderMessage::Year {
+        year,
+        balances,
+        error,
+      });
+    });
+  }
+
+  /// Loads the installment transactions of the given months.
+  pub fn load_installments(&self, storage: &SharedStorage, periods: Vec<YearMonth>) {
+    let sender = self.sender.clone();
+    let storage = storage.clone();
+
+    tokio::spawn(async move {
+      let result = all_months(
+        fetch_months(&storage, periods, |storage, period| async move {
+          storage
+            .get_transactions_by_month_and_type(TransactionType::INSTALLMENTS, &period)
+            .await
+        })
+        .await,
+      )
+      .map(|months| {
+        months
+          .into_iter()
+          .flat_map(|(_, transactions)| transactions)
+          .collect()
+      });
+
+      let _ = sender.send(LoaderMessage::Installments(result));
+    });
+  }
+
+  pub fn check_health(&self, storage: &SharedStorage) {
+    let sender = self.sender.clone();
+    let storage = storage.clone();
+
+    tokio::spawn(async move {
+      let online = storage.health().await;
+      let _ = sender.send(LoaderMessage::Health(online));
+    });
+  }
+
+  /// Sends queued offline changes in order, stopping at the first failure so
+  /// the remaining ones can be retried later.
+  pub fn replay(&self, storage: &SharedStorage, operations: Vec<PendingOperation>) {
+    let sender = self.sender.clone();
+    let storage = storage.clone();
+
+    tokio::spawn(async move {
+      for (index, operation) in operations.iter().enumerate() {
+        let result = match operation {
+          PendingOperation::CreateTransaction(request) => {
+            storage.create_transaction(request.clone()).await
+          }
+          PendingOperation::UpdateTransaction {
+            transaction_id,
+            request,
+          } => {
+            storage
+              .update_transaction(transaction_id, request.clone())
+              .await
+          }
+        };
+
+        if let Err(err) = result {
+          let _ = sender.send(LoaderMessage::Replayed {
+            synced: index,
+            error: Some(err),
+          });
+          return;
+        }
+      }
+
+      let _ = sender.send(LoaderMessage::Replayed {
+        synced: operations.len(),
+        error: None,
+      });
+    });
+  }
+
+  pub fn import(&self, storage: &SharedStorage, requests: Vec<CreateTransactionRequest>) {
+    let sender = self.sender.clone();
+    let storage = storage.clone();
+
+    tokio::spawn(async move {
+      let (created, error) = create_all(storage.as_ref(), requests).await;
+      let _ = sender.send(LoaderMessage::Imported { created, error });
+    });
+  }
+
+  /// Creates the pending occurrences of recurring templates.
+  pub fn generate(&self, storage: &SharedStorage, requests: Vec<CreateTransactionRequest>) {
+    let sender = self.sender.clone();
+    let storage = storage.clone();
+
+    tokio::spawn(async move {
+      let (created, error) = create_all(storage.as_ref(), requests).await;
+      let _ = sender.send(LoaderMessage::Generated { created, error });
+    });
+  }
+
+  /// Creates the transaction, or updates it when `transaction_id` is set.
+  pub fn save_transaction(
+    &self,
+    storage: &SharedStorage,
+    transaction_id: Option<String>,
+    request: CreateTransactionRequest,
+  ) {
+    let sender = self.sender.clone();
+    let storage = storage.clone();
+
+    tokio::spawn(async move {
+      let result = match &transaction_id {
+        Some(id) => storage.update_transaction(id, request).await,
+        None => storage.create_transaction(request).await,
+      }
+      .map(Box::new);
+      let _ = sender.send(LoaderMessage::TransactionSaved {
+        transaction_id,
+        result,
+      });
+    });
+  }
+
+  /// Creates the category, or updates it when `category_id` is set.
+  pub fn save_category(
+    &self,
+    storage: &SharedStorage,
+    category_id: Option<String>,
+    request: CreateCategoryRequest,
+  ) {
+    let sender = self.sender.clone();
+    let storage = storage.clone();
+
+    tokio::spawn(async move {
+      let result = match &category_id {
+        Some(id) => storage.update_category(id, request).await,
+        None => storage.create_category(request).await,
+      };
+      let _ = sender.send(LoaderMessage::CategorySaved {
+        category_id,
+        result,
+      });
+    });
+  }
+
+  /// Deletes a transaction or a category. Recurring templates live in a local
+  /// file and are removed by the app directly.
+  pub fn delete(&self, storage: &SharedStorage, target: DeleteTarget) {
+    let sender = self.sender.clone();
+    let storage = storage.clone();
+
+    tokio::spawn(async move {
+      let result = match &target {
+        DeleteTarget::Transaction(transaction) => {
+          storage
+            .delete_transaction(&transaction.transaction_id)
+            .await
+        }
+        DeleteTarget::Category(category) => storage.delete_category(&category.category_id).await,
+        DeleteTarget::Recurring(_) => Ok(()),
+      };
+      let _ = sender.send(LoaderMessage::Deleted { target, result });
+    });
+  }
+
+  /// Returns false for results of a load that was superseded by a newer one.
+  pub fn finish(&mut self, request_id: u64) -> bool {
+    if request_id != self.request_id {
+      return false;
+    }
+
+    self.in_flight = None;
+    true
+  }
+}
@@ -0,0 +1,88 @@
+use crossterm::event::KeyCode;
+use tui_input::Input;
+
+use crate::{
+  requests::api_client::ApiClient,
+  enums::{language::Language, login_field::LoginField},
+  requests::auth,
+  types::responses::login::{LoginRequest, LoginResponse},
+  utils::input::to_input_request,
+};
+
+pub struct LoginState {
+  pub email: Input,
+  pub password: Input,
+  pub selected_field: LoginField,
+  pub error: Option<String>,
+  pub info: Option<String>,
+  pub session: Option<LoginResponse>,
+  pub api: ApiClient,
+  pub language: Language,
+}
+
+impl LoginState {
+  pub fn new(api: ApiClient, language: Language) -> Self {
+    Self {
+      email: Input::default(),
+      password: Input::default(),
+      selected_field: LoginField::Email,
+      error: None,
+      info: None,
+      session: None,
+      api,
+      language,
+    }
+  }
+
+  fn selected_input(&mut self) -> &mut Input {
+    match self.selected_field {
+      LoginField::Email => &mut self.email,
+      LoginField::Password => &mut self.password,
+    }
+  }
+
+  fn select_next_field(&mut self) {
+    self.selected_field = match self.selected_field {
+      LoginField::Email => LoginField::Password,
+      LoginField::Password => LoginField::Email,
+    };
+  }
+
+  pub async fn login(&mut self) {
+    let email = self.email.value().trim().to_string();
+    let password = self.password.value().to_string();
+
+    if email.is_empty() || password.is_empty() {
+      self.error = Some(String::from("Email and password are required"));
+      return;
+    }
+
+    match auth::login(&self.api, LoginRequest { email, password }).await {
+      Ok(response) => {
+        self.error = None;
+        self.info = None;
+        self.session = Some(response);
+      }
+      Err(err) => {
+        self.info = None;
+        self.password.reset();
+        self.error = Some(err.message(&self.language));
+      }
+    }
+  }
+
+  pub async fn process_key_event(&mut self, key_code: KeyCode) {
+    match key_code {
+      KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => self.select_next_field(),
+      KeyCode::Enter => match self.selected_field {
+        LoginField::Email => self.select_next_field(),
+        LoginField::Password => self.login().await,
+      },
+      _ => {
+        if let Some(request) = to_input_request(key_code) {
+          self.selected_input().handle(request);
+        }
+      }
+    }
+  }
+}
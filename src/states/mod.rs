@@ -1,21 +1,77 @@
+pub mod budget_form;
+pub mod budgets;
+pub mod category_form;
+pub mod charts;
+pub mod export;
+pub mod filter_form;
+pub mod import;
+pub mod installments;
+pub mod loader;
+pub mod login;
+pub mod notifications;
+pub mod recurring;
+pub mod recurring_form;
+pub mod sign_up;
 pub mod tables;
 pub mod tabs;
+pub mod transaction_form;
+pub mod year_overview;
 
-use std::vec;
+use std::{
+  path::PathBuf,
+  time::{Duration, Instant},
+  vec,
+};
+
+use chrono::{DateTime, Local};
 use crate::{
-  enums::selected_block::SelectedBlock,
-  requests::transaction,
-  utils::*,
-  models::transaction::TransactionModel,
-  types::responses::transaction::{TransactionBalances, MonthByYear},
-  ui::get_transactions,
+  enums::{
+    delete_target::DeleteTarget, import_step::ImportStep, language::Language,
+    loader_message::LoaderMessage, pending_operation::PendingOperation,
+    selected_block::SelectedBlock, tab::Tab, transaction_type::TransactionType,
+  },
+  storage::SharedStorage,
+  utils::{
+    budgets::Budgets,
+    cache::{Cache, PENDING_ID_PREFIX},
+    recurring::Recurring,
+    currency::Converter,
+    export::write as write_export,
+    filter::TransactionFilter,
+    input::to_input_request,
+    installments::table_note,
+    *,
+  },
+  models::{category::CategoryModel, custom_error::CustomError, transaction::TransactionModel},
+  types::{
+    money::Money,
+    request::transaction::CreateTransactionRequest,
+    responses::transaction::{TransactionBalances, TransactionTotal, MonthByYear},
+    year_month::YearMonth,
+  },
   states::{
     tabs::TabsState,
     tables::{TransactionsTableState, CustomTableState},
+    transaction_form::TransactionFormState,
+    category_form::CategoryFormState,
+    import::ImportState,
+    budgets::BudgetsState,
+    budget_form::BudgetFormState,
+    charts::ChartsState,
+    installments::InstallmentsState,
+    recurring::RecurringState,
+    recurring_form::RecurringFormState,
+    year_overview::YearOverviewState,
+    export::ExportState,
+    filter_form::FilterFormState,
+    notifications::NotificationsState,
+    loader::{Loader, MonthData},
   },
 };
 use crossterm::event::KeyCode;
-use tui::widgets::TableState;
+use tui_input::Input;
+
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(15);
 
 #[derive(Debug)]
 pub enum SortMode {
@@ -26,17 +82,42 @@ pub enum SortMode {
 pub struct App<'a> {
   pub tree: StatefulTree<'a>,
   pub selected_block: SelectedBlock,
-  pub user_token: String,
   pub is_navigation: bool,
   pub transactions_header: Vec<&'static str>,
   pub table_state: TransactionsTableState,
   pub summary: TransactionBalances,
   pub months_by_year: Vec<MonthByYear>,
-  pub client: reqwest::Client,
+  pub storage: SharedStorage,
+  pub language: Language,
+  pub converter: Converter,
   pub tabs: TabsState<'a>,
   pub details_shown: bool,
-  pub total_saving: f64,
+  pub total_saving: TransactionTotal,
   pub await_data: bool,
+  pub session_expired: bool,
+  pub transaction_form: Option<TransactionFormState>,
+  pub delete_confirmation: Option<DeleteTarget>,
+  pub categories: Vec<CategoryModel>,
+  pub categories_table: CustomTableState,
+  pub category_form: Option<CategoryFormState>,
+  pub budgets: BudgetsState,
+  pub budget_form: Option<BudgetFormState>,
+  pub charts: ChartsState,
+  pub installments: InstallmentsState,
+  pub recurring: RecurringState,
+  pub recurring_form: Option<RecurringFormState>,
+  pub year_overview: Option<YearOverviewState>,
+  pub filter: TransactionFilter,
+  /// The `/` search bar, edits `filter.text` as it's typed.
+  pub search: Option<Input>,
+  pub filter_form: Option<FilterFormState>,
+  pub import: Option<ImportState>,
+  pub export: Option<ExportState>,
+  pub notifications: NotificationsState,
+  pub loader: Loader,
+  pub cache: Cache,
+  pub offline: bool,
+  last_health_check: Instant,
 }
 
 pub struct DataTable<'a> {
@@ -46,258 +127,1181 @@ pub struct DataTable<'a> {
   pub savings: Vec<TransactionModel>,
   pub summary: TransactionBalances,
   pub tree: StatefulTree<'a>,
-  pub total_saving: f64,
+  pub total_saving: TransactionTotal,
+  pub categories: Vec<CategoryModel>,
+  pub refreshed_at: DateTime<Local>,
 }
 
-fn get_transactions_row(transactions: &[TransactionModel]) -> Vec<Vec<String>> {
-  transactions
+fn get_transaction_row(
+  transaction: &TransactionModel,
+  converter: &Converter,
+  language: &Language,
+) -> Vec<String> {
+  vec![
+    match transaction.day {
+      Some(day) => day.to_string(),
+      None => "".to_string(),
+    },
+    match converter.convert_transaction(transaction) {
+      Some(amount) if transaction.currency != converter.display => format!(
+        "{} ≈ {}",
+        transaction.money().format(language),
+        Money::new(amount, converter.display.clone()).format(language)
+      ),
+      _ => transaction.money().format(language),
+    },
+    table_note(transaction).unwrap_or_default(),
+    match &transaction.category {
+      Some(category) => category.name.to_string(),
+      None => "".to_string(),
+    },
+  ]
+}
+
+/// Rebuilds `table` with the transactions matching `filter`.
+fn set_transactions_rows(
+  table: &mut CustomTableState,
+  transactions: &[TransactionModel],
+  filter: &TransactionFilter,
+  converter: &Converter,
+  language: &Language,
+) {
+  (table.source, table.items) = transactions
     .iter()
-    .map(|transaction: &TransactionModel| {
+    .enumerate()
+    .filter(|(_, transaction)| filter.matches(transaction))
+    .map(|(i, transaction)| (i, get_transaction_row(transaction, converter, language)))
+    // Skips the day, "5" shouldn't match every transaction of the 5th.
+    .filter(|(_, row)| filter.matches_text(&row[1..]))
+    .unzip();
+
+  table.clamp_selection();
+}
+
+fn get_categories_row(categories: &[CategoryModel]) -> Vec<Vec<String>> {
+  categories
+    .iter()
+    .map(|category: &CategoryModel| {
       vec![
-        match transaction.day {
-          Some(day) => day.to_string(),
-          None => "".to_string(),
-        },
-        format!("${:?} {:?}", transaction.amount, transaction.currency),
-        match &transaction.note {
+        category.name.to_string(),
+        format!("{:?}", category.r#type),
+        match &category.note {
           Some(note) => note.to_string(),
           None => "".to_string(),
         },
-        // match &expense.category {
-        //   Some(category) => {
-        //     if category.name.len() > 15 {
-        //       wrap_text(&category.name, 15)
-        //     } else {
-        //       category.name.to_string()
-        //     }
-        //   }
-        //   None => "".to_string(),
-        // },
       ]
     })
     .collect()
 }
 
 impl<'a> App<'a> {
-  pub fn new(data_table: DataTable<'a>, user_token: String, client: reqwest::Client) -> App<'a> {
-    let expenses_table = CustomTableState {
-      state: TableState::default(),
-      items: get_transactions_row(&data_table.expenses),
-    };
+  pub fn new(
+    data_table: DataTable<'a>,
+    storage: SharedStorage,
+    language: Language,
+    converter: Converter,
+    loader: Loader,
+    cache: Cache,
+    offline: bool,
+  ) -> App<'a> {
+    let mut notifications = NotificationsState::default();
+    notifications.refreshed(data_table.refreshed_at);
 
-    let incomes_table = CustomTableState {
-      state: TableState::default(),
-      items: get_transactions_row(&data_table.incomes),
-    };
+    let store = Budgets::load().unwrap_or_else(|err| {
+      notifications.error(format!("Budgets not loaded: {}", err.message(&language)));
+      Budgets::disabled()
+    });
+    let mut budgets = BudgetsState::new(store);
+    budgets.update(&data_table.categories, &data_table.expenses, &converter);
 
-    let savings_table = CustomTableState {
-      state: TableState::default(),
-      items: get_transactions_row(&data_table.savings),
-    };
+    let store = Recurring::load().unwrap_or_else(|err| {
+      notifications.error(format!(
+        "Recurring transactions not loaded: {}",
+        err.message(&language)
+      ));
+      Recurring::disabled()
+    });
+    let mut recurring = RecurringState::new(store);
+    recurring.check(&data_table.tree.current);
 
-    Self {
-      client,
+    if offline {
+      notifications.warn("Server unreachable, showing cached data");
+    } else if !cache.data.pending.is_empty() {
+      loader.replay(&storage, cache.data.pending.clone());
+    }
+
+    let mut app = Self {
+      storage,
+      language,
+      converter,
       months_by_year: data_table.months_by_year,
       tree: data_table.tree,
       selected_block: SelectedBlock::Months,
-      user_token,
       is_navigation: true,
       table_state: TransactionsTableState::new(
-        incomes_table,
-        expenses_table,
-        savings_table,
+        CustomTableState::new(Vec::new()),
+        CustomTableState::new(Vec::new()),
+        CustomTableState::new(Vec::new()),
         data_table.expenses,
         data_table.incomes,
         data_table.savings,
       ),
-      transactions_header: vec!["Day", "Amount", "Note"],
+      transactions_header: vec!["Day", "Amount", "Note", "Category"],
       summary: data_table.summary,
       tabs: TabsState::default(),
       details_shown: false,
       total_saving: data_table.total_saving,
       await_data: false,
+      session_expired: false,
+      transaction_form: None,
+      delete_confirmation: None,
+      categories_table: CustomTableState::new(get_categories_row(&data_table.categories)),
+      categories: data_table.categories,
+      category_form: None,
+      budgets,
+      budget_form: None,
+      charts: ChartsState::default(),
+      installments: InstallmentsState::default(),
+      recurring,
+      recurring_form: None,
+      year_overview: None,
+      filter: TransactionFilter::default(),
+      search: None,
+      filter_form: None,
+      import: None,
+      export: None,
+      notifications,
+      loader,
+      cache,
+      offline,
+      last_health_check: Instant::now(),
+    };
+
+    app.update_transaction_rows();
+    app
+  }
+
+  pub fn is_input_mode(&self) -> bool {
+    self.transaction_form.is_some()
+      || self.category_form.is_some()
+      || self.budget_form.is_some()
+      || self.import.is_some()
+      || self.export.is_some()
+      || self.year_overview.is_some()
+      || self.recurring_form.is_some()
+      || self.search.is_some()
+      || self.filter_form.is_some()
+      || self.recurring.prompt.is_some()
+      || self.delete_confirmation.is_some()
+  }
+
+  fn report_error(&mut self, err: CustomError) {
+    if err.is_unauthorized() {
+      self.session_expired = true;
+    } else {
+      self.notifications.error(err.message(&self.language));
+    }
+  }
+
+  fn save_cache(&mut self) {
+    if let Err(err) = self.cache.save() {
+      self.report_error(err);
+    }
+  }
+
+  /// Called on every iteration of the run loop.
+  pub fn tick(&mut self) {
+    self.notifications.tick();
+
+    if self.offline && self.last_health_check.elapsed() >= HEALTH_CHECK_INTERVAL {
+      self.check_health();
+    }
+  }
+
+  /// Queues further changes and shows cached data until the health check
+  /// reaches the server again.
+  fn go_offline(&mut self) {
+    self.offline = true;
+    self.last_health_check = Instant::now();
+    self
+      .notifications
+      .warn("Server unreachable, changes will sync when back online");
+  }
+
+  fn check_health(&mut self) {
+    self.last_health_check = Instant::now();
+    self.loader.check_health(&self.storage);
+  }
+
+  /// Reloads the current month in the background, results arrive through
+  /// `process_message`. While offline the month is read from the cache.
+  pub fn refresh(&mut self) {
+    if self.offline {
+      return self.load_cached_month();
+    }
+
+    self.await_data = true;
+    self
+      .loader
+      .load_month(&self.storage, self.tree.current.clone());
+  }
+
+  fn load_cached_month(&mut self) {
+    match self.cache.month(&self.tree.current).cloned() {
+      Some(month) => self.set_transactions(month.data, month.fetched_at),
+      None => {
+        let message = format!("No cached data for {}", self.tree.current);
+        self.set_transactions(MonthData::default(), Local::now());
+        self.notifications.warn(&message);
+      }
+    }
+  }
+
+  /// Loads the balances of every month for the Charts tab, read from the
+  /// cache while offline.
+  fn refresh_trends(&mut self) {
+    let periods = charts::periods(&self.months_by_year);
+
+    if self.offline {
+      let trends = periods
+        .into_iter()
+        .filter_map(|period| {
+          let summary = self.cache.month(&period)?.data.summary.clone();
+          Some((period, summary))
+        })
+        .collect();
+
+      return self.charts.set_trends(trends);
+    }
+
+    self.charts.loading = true;
+    self.loader.load_trends(&self.storage, periods);
+  }
+
+  /// Loads the installment transactions of every month, read from the cache
+  /// while offline.
+  fn refresh_installments(&mut self) {
+    let periods = charts::periods(&self.months_by_year);
+
+    if self.offline {
+      let transactions: Vec<TransactionModel> = periods
+        .iter()
+        .filter_map(|period| self.cache.month(period))
+        .flat_map(|month| month.data.expenses.iter().cloned())
+        .collect();
+
+      return self.installments.set_transactions(&transactions);
+    }
+
+    self.installments.loading = true;
+    self.loader.load_installments(&self.storage, periods);
+  }
+
+  /// Marks the data spanning several months as outdated, reloading it right
+  /// away if it is shown.
+  fn invalidate_history(&mut self) {
+    self.charts.stale = true;
+    self.installments.stale = true;
+    self.on_tab_changed();
+  }
+
+  fn on_tab_changed(&mut self) {
+    match self.tabs.current() {
+      Tab::Charts if self.charts.stale && !self.charts.loading => self.refresh_trends(),
+      Tab::Installments if self.installments.stale && !self.installments.loading => {
+        self.refresh_installments()
+      }
+      _ => (),
+    }
+  }
+
+  /// Opens the overview of a year, loading the balances of its months. While
+  /// offline they are read from the cache.
+  fn open_year_overview(&mut self, year: i32) {
+    let mut overview = YearOverviewState::new(year, &self.tree.current);
+
+    if self.offline {
+      let balances: Vec<_> = YearMonth::months_of(year)
+        .into_iter()
+        .filter_map(|period| {
+          let summary = self.cache.month(&period)?.data.summary.clone();
+          Some((period, summary))
+        })
+        .collect();
+
+      overview.set_balances(&balances);
+    } else {
+      self.loader.load_year(&self.storage, year);
     }
+
+    self.year_overview = Some(overview);
+  }
+
+  fn open_overview_month(&mut self) {
+    let Some(period) = self
+      .year_overview
+      .take()
+      .and_then(|overview| overview.selected_period())
+    else {
+      return;
+    };
+
+    self.tree.current = period;
+    self.refresh();
   }
 
-  pub async fn set_transactions(&mut self) {
-    if !self.await_data {
-      self.await_data = true;
-      let transactions = get_transactions(&self.client, &self.user_token, &self.tree).await;
-      let total_saving = transaction::get_total_saving(&self.client, &self.user_token).await;
+  pub fn refresh_categories(&self) {
+    if !self.offline {
+      self.loader.load_categories(&self.storage);
+    }
+  }
+
+  pub fn process_message(&mut self, message: LoaderMessage) {
+    match message {
+      LoaderMessage::Month {
+        request_id,
+        period,
+        result,
+      } => {
+        if !self.loader.finish(request_id) {
+          return;
+        }
+
+        self.await_data = false;
+
+        match result {
+          Ok(data) => {
+            self.cache.store_month(&period, data.clone());
+            self.save_cache();
+            self.set_transactions(data, Local::now());
+          }
+          Err(err) => self.report_error(err),
+        }
+      }
+      LoaderMessage::Categories(result) => match result {
+        Ok(categories) => {
+          self.cache.data.categories = categories.clone();
+          self.save_cache();
+          self.set_categories(categories);
+        }
+        Err(err) => self.report_error(err),
+      },
+      LoaderMessage::Trends(result) => match result {
+        Ok(trends) => self.charts.set_trends(trends),
+        Err(err) => {
+          self.charts.loading = false;
+          self.report_error(err);
+        }
+      },
+      LoaderMessage::Installments(result) => match result {
+        Ok(transactions) => self.installments.set_transactions(&transactions),
+        Err(err) => {
+          self.installments.loading = false;
+          self.report_error(err);
+        }
+      },
+      LoaderMessage::Year {
+        year,
+        balances,
+        error,
+      } => {
+        let Some(overview) = self
+          .year_overview
+          .as_mut()
+          .filter(|overview| overview.year == year)
+        else {
+          return;
+        };
+
+        overview.set_balances(&balances);
+
+        if let Some(err) = error {
+          self.report_error(err);
+        }
+      }
+      LoaderMessage::Health(online) => {
+        if !online || !self.offline {
+          return;
+        }
+
+        self.offline = false;
+        self.notifications.info("Back online");
+        self.invalidate_history();
+
+        if self.cache.data.pending.is_empty() {
+          self.refresh();
+          self.refresh_categories();
+        } else {
+          self
+            .loader
+            .replay(&self.storage, self.cache.data.pending.clone());
+        }
+      }
+      LoaderMessage::Replayed { synced, error } => {
+        // Changes queued while the replay was running stay after the synced ones.
+        let synced = synced.min(self.cache.data.pending.len());
+        self.cache.data.pending.drain(..synced);
+        self.save_cache();
+
+        if synced > 0 {
+          let message = format!("Synced {} offline change(s)", synced);
+          self.notifications.info(&message);
+        }
+
+        match error {
+          Some(err) if err.is_network() => return self.go_offline(),
+          Some(err) => self.report_error(err),
+          None => (),
+        }
+
+        self.refresh();
+        self.refresh_categories();
+        self.invalidate_history();
+      }
+      LoaderMessage::Generated { created, error } => {
+        let generated = std::mem::take(&mut self.recurring.generating);
 
-      match total_saving {
-        Ok(data) => {
-          self.total_saving = data;
+        if let Err(err) = self.recurring.store.mark_generated(&generated[..created]) {
+          self.report_error(err);
         }
-        Err(err) => println!("{:?}", err),
-      }
-
-      match transactions {
-        Ok(mut result) => {
-          transaction_utils::sort(&mut result.0);
-          transaction_utils::sort(&mut result.1);
-          transaction_utils::sort(&mut result.2);
-
-          self.table_state.expenses = CustomTableState {
-            state: self.table_state.expenses.state.clone(),
-            items: get_transactions_row(&result.0),
-          };
-          self.table_state.incomes = CustomTableState {
-            state: self.table_state.incomes.state.clone(),
-            items: get_transactions_row(&result.1),
-          };
-          self.table_state.savings = CustomTableState {
-            state: self.table_state.savings.state.clone(),
-            items: get_transactions_row(&result.2),
-          };
-
-          match transaction::get_transactions_balances(
-            &self.client,
-            &self.user_token,
-            self.tree.current_month.clone(),
-          )
-          .await
-          {
-            Ok(data) => self.summary = data,
-            Err(_) => println!("errr"),
-          };
-          self.await_data = false;
+
+        let message = format!("Created {} recurring transaction(s)", created);
+        self.notifications.info(&message);
+
+        if let Some(err) = error {
+          self.report_error(err);
         }
-        Err(_) => {
-          self.await_data = false;
-          println!("error");
+
+        self.refresh();
+        self.invalidate_history();
+      }
+      LoaderMessage::TransactionSaved {
+        transaction_id,
+        result,
+      } => self.on_transaction_saved(transaction_id, result),
+      LoaderMessage::CategorySaved {
+        category_id,
+        result,
+      } => self.on_category_saved(category_id, result),
+      LoaderMessage::Deleted { target, result } => self.on_deleted(target, result),
+      LoaderMessage::Imported { created, error } => {
+        let message = format!("Imported {} transaction(s)", created);
+        self.notifications.info(&message);
+
+        if let Some(err) = error {
+          self.report_error(err);
         }
+
+        self.refresh();
+        self.invalidate_history();
       }
     }
   }
 
-  fn select_next_block(&mut self) {
-    match self.tabs.index {
-      0 => {
-        match self.selected_block {
-          SelectedBlock::Months => self.selected_block = SelectedBlock::Tabs,
-          SelectedBlock::Tabs => self.selected_block = SelectedBlock::Incomes,
-          SelectedBlock::Incomes => self.selected_block = SelectedBlock::Expenses,
-          SelectedBlock::Expenses => self.selected_block = SelectedBlock::Savings,
-          SelectedBlock::Savings => self.selected_block = SelectedBlock::Months,
-          _ => (),
-        };
+  fn set_transactions(&mut self, data: MonthData, refreshed_at: DateTime<Local>) {
+    self.total_saving = data.total_saving;
+    self.summary = data.summary;
+
+    self.table_state.row_expenses = data.expenses;
+    self.table_state.row_incomes = data.incomes;
+    self.table_state.row_savings = data.savings;
+    self.update_transaction_rows();
+    self.update_budgets();
+    self.charts.update_month(&self.tree.current, &self.summary);
+    self.recurring.check(&self.tree.current);
+
+    self.notifications.refreshed(refreshed_at);
+  }
+
+  fn update_budgets(&mut self) {
+    self.budgets.update(
+      &self.categories,
+      &self.table_state.row_expenses,
+      &self.converter,
+    );
+  }
+
+  fn open_budget_form(&mut self) {
+    self.budget_form = self.budgets.selected_row().map(BudgetFormState::from_row);
+  }
+
+  fn submit_budget_form(&mut self) {
+    let Some(form) = &mut self.budget_form else {
+      return;
+    };
+
+    let limit = match form.to_limit() {
+      Ok(limit) => limit,
+      Err(err) => {
+        form.error = Some(err);
+        return;
       }
-      1 => {
-        match self.selected_block {
-          SelectedBlock::Months => self.selected_block = SelectedBlock::Tabs,
-          SelectedBlock::Tabs => self.selected_block = SelectedBlock::Expenses,
-          SelectedBlock::Expenses => self.selected_block = SelectedBlock::Months,
-          SelectedBlock::Incomes => (),
-          SelectedBlock::Savings => (),
-          _ => (),
+    };
+
+    match self.budgets.set_limit(form.category_id.as_deref(), limit) {
+      Ok(_) => {
+        let message = match limit {
+          Some(_) => format!("Budget for {} saved", form.name),
+          None => format!("Budget for {} removed", form.name),
         };
+        self.budget_form = None;
+        self.notifications.info(&message);
       }
-      2 => {
-        match self.selected_block {
-          SelectedBlock::Months => self.selected_block = SelectedBlock::Tabs,
-          SelectedBlock::Tabs => self.selected_block = SelectedBlock::Incomes,
-          SelectedBlock::Incomes => self.selected_block = SelectedBlock::Months,
-          SelectedBlock::Expenses => (),
-          SelectedBlock::Savings => (),
-          _ => (),
-        };
+      Err(err) => form.error = Some(err.message(&self.language)),
+    }
+  }
+
+  fn clear_budget(&mut self) {
+    let Some(row) = self.budgets.selected_row() else {
+      return;
+    };
+
+    if row.limit.is_none() {
+      return;
+    }
+
+    let category_id = row.category_id.clone();
+    let message = format!("Budget for {} removed", row.name);
+
+    match self.budgets.set_limit(category_id.as_deref(), None) {
+      Ok(_) => self.notifications.info(&message),
+      Err(err) => self.report_error(err),
+    }
+  }
+
+  fn update_transaction_rows(&mut self) {
+    let table_state = &mut self.table_state;
+
+    for (table, transactions) in [
+      (&mut table_state.expenses, &table_state.row_expenses),
+      (&mut table_state.incomes, &table_state.row_incomes),
+      (&mut table_state.savings, &table_state.row_savings),
+    ] {
+      set_transactions_rows(
+        table,
+        transactions,
+        &self.filter,
+        &self.converter,
+        &self.language,
+      );
+    }
+  }
+
+  fn set_filter(&mut self, filter: TransactionFilter) {
+    self.filter = filter;
+    self.update_transaction_rows();
+  }
+
+  fn submit_filter_form(&mut self) {
+    let Some(form) = &mut self.filter_form else {
+      return;
+    };
+
+    match form.to_filter() {
+      Ok(filter) => {
+        self.filter_form = None;
+        self.set_filter(filter);
       }
-      3 => {
-        match self.selected_block {
-          SelectedBlock::Months => self.selected_block = SelectedBlock::Tabs,
-          SelectedBlock::Tabs => self.selected_block = SelectedBlock::Savings,
-          SelectedBlock::Savings => self.selected_block = SelectedBlock::Months,
-          SelectedBlock::Expenses => (),
-          SelectedBlock::Incomes => (),
-          _ => (),
-        };
+      Err(err) => form.error = Some(err),
+    }
+  }
+
+  fn cycle_display_currency(&mut self) {
+    self.converter.cycle_display();
+    self.update_transaction_rows();
+
+    let message = format!("Showing totals in {}", self.converter.display);
+    self.notifications.info(&message);
+  }
+
+  fn set_categories(&mut self, categories: Vec<CategoryModel>) {
+    self.categories_table.items = get_categories_row(&categories);
+    self.categories_table.clamp_selection();
+    self.categories = categories;
+    self.update_budgets();
+  }
+
+  fn selected_category(&self) -> Option<&CategoryModel> {
+    self
+      .categories_table
+      .state
+      .selected()
+      .and_then(|i| self.categories.get(i))
+  }
+
+  fn open_recurring_form(&mut self) {
+    let categories = self.categories.clone();
+
+    self.recurring_form = Some(match self.recurring.selected_template() {
+      Some(template) if self.selected_block == SelectedBlock::Recurring => {
+        RecurringFormState::from_template(template, categories)
       }
-      _ => (),
+      _ => RecurringFormState::new(categories),
+    });
+  }
+
+  fn submit_recurring_form(&mut self) {
+    let Some(form) = &mut self.recurring_form else {
+      return;
+    };
+
+    let template = match form.to_template() {
+      Ok(template) => template,
+      Err(err) => {
+        form.error = Some(err);
+        return;
+      }
+    };
+
+    let message = match form.template_id {
+      Some(_) => "Recurring transaction updated",
+      None => "Recurring transaction created",
+    };
+
+    match self.recurring.store.save(template) {
+      Ok(_) => {
+        self.recurring_form = None;
+        self.notifications.info(message);
+        self.recurring.check(&self.tree.current);
+      }
+      Err(err) => form.error = Some(err.message(&self.language)),
     }
   }
 
-  fn select_previous_block(&mut self) {
-    match self.tabs.index {
-      0 => {
-        match self.selected_block {
-          SelectedBlock::Months => self.selected_block = SelectedBlock::Savings,
-          SelectedBlock::Tabs => self.selected_block = SelectedBlock::Months,
-          SelectedBlock::Incomes => self.selected_block = SelectedBlock::Tabs,
-          SelectedBlock::Expenses => self.selected_block = SelectedBlock::Incomes,
-          SelectedBlock::Savings => self.selected_block = SelectedBlock::Expenses,
-          _ => (),
-        };
+  /// Creates the occurrences of the prompt, queued while offline.
+  fn generate_recurring(&mut self) {
+    let Some(prompt) = self.recurring.prompt.take() else {
+      return;
+    };
+
+    let requests: Vec<CreateTransactionRequest> = prompt
+      .occurrences
+      .iter()
+      .map(|occurrence| occurrence.request.clone())
+      .collect();
+
+    if self.offline {
+      let count = requests.len();
+      for request in requests {
+        self
+          .cache
+          .queue(PendingOperation::CreateTransaction(request));
       }
-      1 => {
-        match self.selected_block {
-          SelectedBlock::Months => self.selected_block = SelectedBlock::Expenses,
-          SelectedBlock::Tabs => self.selected_block = SelectedBlock::Months,
-          SelectedBlock::Expenses => self.selected_block = SelectedBlock::Tabs,
-          SelectedBlock::Incomes => (),
-          SelectedBlock::Savings => (),
-          _ => (),
-        };
+      self.save_cache();
+      self.load_cached_month();
+
+      if let Err(err) = self.recurring.store.mark_generated(&prompt.occurrences) {
+        self.report_error(err);
       }
-      2 => {
-        match self.selected_block {
-          SelectedBlock::Months => self.selected_block = SelectedBlock::Incomes,
-          SelectedBlock::Tabs => self.selected_block = SelectedBlock::Months,
-          SelectedBlock::Incomes => self.selected_block = SelectedBlock::Tabs,
-          SelectedBlock::Expenses => (),
-          SelectedBlock::Savings => (),
-          _ => (),
-        };
+
+      let message = format!("{} transaction(s) will be created when back online", count);
+      return self.notifications.info(&message);
+    }
+
+    self.recurring.generating = prompt.occurrences;
+    self.loader.generate(&self.storage, requests);
+  }
+
+  /// Marks the occurrences of the prompt as handled without creating them.
+  fn skip_recurring(&mut self) {
+    let Some(prompt) = self.recurring.prompt.take() else {
+      return;
+    };
+
+    match self.recurring.store.mark_generated(&prompt.occurrences) {
+      Ok(_) => self.notifications.info("Recurring transactions skipped"),
+      Err(err) => self.report_error(err),
+    }
+  }
+
+  fn open_category_form(&mut self) {
+    self.category_form = Some(CategoryFormState::new());
+  }
+
+  fn open_edit_category_form(&mut self) {
+    match self.selected_category() {
+      Some(category) => self.category_form = Some(CategoryFormState::from_category(category)),
+      None => self.notifications.warn("Select a category first"),
+    }
+  }
+
+  fn submit_category_form(&mut self) {
+    let Some(form) = &mut self.category_form else {
+      return;
+    };
+
+    if form.saving {
+      return;
+    }
+
+    match form.to_request() {
+      Ok(request) => {
+        form.saving = true;
+        form.error = None;
+        self
+          .loader
+          .save_category(&self.storage, form.category_id.clone(), request);
       }
-      3 => {
-        match self.selected_block {
-          SelectedBlock::Months => self.selected_block = SelectedBlock::Savings,
-          SelectedBlock::Tabs => self.selected_block = SelectedBlock::Months,
-          SelectedBlock::Savings => self.selected_block = SelectedBlock::Tabs,
-          SelectedBlock::Expenses => (),
-          SelectedBlock::Incomes => (),
-          _ => (),
-        };
+      Err(err) => form.error = Some(err),
+    }
+  }
+
+  fn on_category_saved(
+    &mut self,
+    category_id: Option<String>,
+    result: Result<CategoryModel, CustomError>,
+  ) {
+    match result {
+      Ok(_) => {
+        self.category_form = None;
+        self.refresh_categories();
+        if category_id.is_some() {
+          self.notifications.info("Category updated");
+          self.refresh();
+        } else {
+          self.notifications.info("Category created");
+        }
       }
-      _ => (),
+      Err(err) if err.is_unauthorized() => self.session_expired = true,
+      // The form may have been closed while the request was running.
+      Err(err) => match &mut self.category_form {
+        Some(form) => {
+          form.saving = false;
+          form.error = Some(err.message(&self.language));
+        }
+        None => self.report_error(err),
+      },
     }
   }
 
-  pub async fn process_key_event(&mut self, key_code: KeyCode) {
-    match key_code {
-      KeyCode::Char('j') => {
-        if self.is_navigation {
-          self.select_next_block()
+  fn open_transaction_form(&mut self) {
+    let transaction_type = match self.selected_block {
+      SelectedBlock::Incomes => TransactionType::INCOME,
+      SelectedBlock::Savings => TransactionType::SAVING,
+      _ => match self.tabs.current() {
+        Tab::Incomes => TransactionType::INCOME,
+        Tab::Savings => TransactionType::SAVING,
+        _ => TransactionType::EXPENSE,
+      },
+    };
+
+    self.transaction_form = Some(TransactionFormState::new(
+      transaction_type,
+      &self.tree.current,
+      self.categories.clone(),
+    ));
+  }
+
+  fn open_edit_transaction_form(&mut self) {
+    if let Some(transaction) = &self.table_state.transactions_details {
+      if transaction.transaction_id.starts_with(PENDING_ID_PREFIX) {
+        return self
+          .notifications
+          .warn("This transaction can be edited once it is synced");
+      }
+
+      self.transaction_form = Some(TransactionFormState::from_transaction(
+        transaction,
+        self.categories.clone(),
+      ));
+    }
+  }
+
+  fn submit_transaction_form(&mut self) {
+    let Some(form) = &mut self.transaction_form else {
+      return;
+    };
+
+    if form.saving {
+      return;
+    }
+
+    let request = match form.to_request() {
+      Ok(request) => request,
+      Err(err) => {
+        form.error = Some(err);
+        return;
+      }
+    };
+    let transaction_id = form.transaction_id.clone();
+
+    if self.offline {
+      return self.queue_transaction(transaction_id, request);
+    }
+
+    form.saving = true;
+    form.error = None;
+    self
+      .loader
+      .save_transaction(&self.storage, transaction_id, request);
+  }
+
+  fn on_transaction_saved(
+    &mut self,
+    transaction_id: Option<String>,
+    result: Result<Box<TransactionModel>, CustomError>,
+  ) {
+    match result {
+      Ok(saved) => {
+        self.transaction_form = None;
+        if transaction_id.is_some() {
+          self.table_state.transactions_details = Some(*saved);
+          self.notifications.info("Transaction updated");
+        } else {
+          self.notifications.info("Transaction created");
         }
+        self.refresh();
+        self.invalidate_history();
       }
-      KeyCode::Char('k') => {
-        if self.is_navigation {
-          self.select_previous_block()
+      Err(err) if err.is_unauthorized() => self.session_expired = true,
+      // The form may have been closed while the request was running.
+      Err(err) => match &mut self.transaction_form {
+        Some(form) => {
+          form.saving = false;
+          form.error = Some(err.message(&self.language));
         }
+        None => self.report_error(err),
+      },
+    }
+  }
+
+  /// Stores a create/edit made while offline so it is sent once the server is reachable.
+  fn queue_transaction(
+    &mut self,
+    transaction_id: Option<String>,
+    request: CreateTransactionRequest,
+  ) {
+    self.cache.queue(match transaction_id.clone() {
+      Some(transaction_id) => PendingOperation::UpdateTransaction {
+        transaction_id,
+        request,
+      },
+      None => PendingOperation::CreateTransaction(request),
+    });
+    self.save_cache();
+    self.load_cached_month();
+
+    if let Some(transaction_id) = transaction_id {
+      self.table_state.transactions_details = self
+        .cache
+        .data
+        .months
+        .values()
+        .flat_map(|month| {
+          month
+            .data
+            .expenses
+            .iter()
+            .chain(&month.data.incomes)
+            .chain(&month.data.savings)
+        })
+        .find(|transaction| transaction.transaction_id == transaction_id)
+        .cloned();
+    }
+
+    self.transaction_form = None;
+    self
+      .notifications
+      .info("Saved offline, it will sync when back online");
+  }
+
+  /// Transactions already known to the app, used to flag duplicated imports.
+  fn known_transactions(&self) -> Vec<TransactionModel> {
+    let mut known: Vec<TransactionModel> = self
+      .cache
+      .data
+      .months
+      .values()
+      .flat_map(|month| {
+        month
+          .data
+          .expenses
+          .iter()
+          .chain(&month.data.incomes)
+          .chain(&month.data.savings)
+          .cloned()
+      })
+      .collect();
+
+    known.extend(self.table_state.row_expenses.iter().cloned());
+    known.extend(self.table_state.row_incomes.iter().cloned());
+    known.extend(self.table_state.row_savings.iter().cloned());
+
+    known
+  }
+
+  fn submit_import(&mut self) {
+    let requests = match self.import.take() {
+      Some(import) => import.selected_requests(),
+      None => return,
+    };
+
+    if requests.is_empty() {
+      return self.notifications.warn("Nothing selected to import");
+    }
+
+    if self.offline {
+      let count = requests.len();
+      for request in requests {
+        self
+          .cache
+          .queue(PendingOperation::CreateTransaction(request));
       }
-      KeyCode::Enter => match self.selected_block {
-        SelectedBlock::Savings => {
-          if let Some(i) = self.table_state.savings.state.selected() {
-            self.details_shown = true;
-            self.table_state.transactions_details = Some(self.table_state.row_savings[i].clone());
+      self.save_cache();
+      self.load_cached_month();
+
+      let message = format!("{} transaction(s) will be imported when back online", count);
+      return self.notifications.info(&message);
+    }
+
+    let message = format!("Importing {} transaction(s)...", requests.len());
+    self.notifications.info(&message);
+    self.loader.import(&self.storage, requests);
+  }
+
+  fn current_month_data(&self) -> MonthData {
+    MonthData {
+      expenses: self.table_state.row_expenses.clone(),
+      incomes: self.table_state.row_incomes.clone(),
+      savings: self.table_state.row_savings.clone(),
+      summary: self.summary.clone(),
+      total_saving: self.total_saving.clone(),
+    }
+  }
+
+  fn submit_export(&mut self) {
+    let data = self.current_month_data();
+
+    let Some(export) = &mut self.export else {
+      return;
+    };
+
+    let path = PathBuf::from(export.path.value().trim());
+    let months = [(export.period.clone(), data)];
+
+    match write_export(&path, &export.format, &months) {
+      Ok(_) => {
+        self.export = None;
+        self
+          .notifications
+          .info(&format!("Exported to {}", path.display()));
+      }
+      Err(err) => export.error = Some(err.message(&self.language)),
+    }
+  }
+
+  fn delete_target(&mut self) {
+    match self.delete_confirmation.take() {
+      Some(DeleteTarget::Recurring(template)) => match self.recurring.store.remove(&template.id) {
+        Ok(_) => {
+          self.recurring.clamp_selection();
+          self.notifications.info("Recurring transaction deleted");
+        }
+        Err(err) => self.report_error(err),
+      },
+      Some(target) => self.loader.delete(&self.storage, target),
+      None => (),
+    }
+  }
+
+  fn on_deleted(&mut self, target: DeleteTarget, result: Result<(), CustomError>) {
+    let message = match target {
+      DeleteTarget::Category(_) => "Category deleted",
+      _ => "Transaction deleted",
+    };
+
+    match result {
+      Ok(_) => {
+        self.details_shown = false;
+        self.table_state.transactions_details = None;
+        self.notifications.info(message);
+        self.refresh_categories();
+        self.refresh();
+        self.invalidate_history();
+      }
+      Err(err) => self.report_error(err),
+    }
+  }
+
+  /// Blocks of the current tab in the order `j` walks through them.
+  fn tab_blocks(&self) -> &'static [SelectedBlock] {
+    use SelectedBlock::*;
+
+    match self.tabs.current() {
+      Tab::All => &[Months, Tabs, Incomes, Expenses, Savings],
+      Tab::Expenses => &[Months, Tabs, Expenses],
+      Tab::Incomes => &[Months, Tabs, Incomes],
+      Tab::Savings => &[Months, Tabs, Savings],
+      Tab::Categories => &[Months, Tabs, Categories],
+      Tab::Budgets => &[Months, Tabs, Budgets],
+      Tab::Charts => &[Months, Tabs, Charts],
+      Tab::Installments => &[Months, Tabs, Installments],
+      Tab::Recurring => &[Months, Tabs, Recurring],
+    }
+  }
+
+  /// Moves to the next or previous block of the tab, wrapping around.
+  fn select_block(&mut self, forward: bool) {
+    let blocks = self.tab_blocks();
+
+    if let Some(i) = blocks
+      .iter()
+      .position(|block| *block == self.selected_block)
+    {
+      let next = if forward {
+        (i + 1) % blocks.len()
+      } else {
+        (i + blocks.len() - 1) % blocks.len()
+      };
+      self.selected_block = blocks[next].clone();
+    }
+  }
+
+  pub fn process_key_event(&mut self, key_code: KeyCode) {
+    if let Some(form) = &mut self.transaction_form {
+      match key_code {
+        KeyCode::Esc => self.transaction_form = None,
+        KeyCode::Enter => self.submit_transaction_form(),
+        _ => form.process_key_event(key_code),
+      }
+      return;
+    }
+
+    if let Some(form) = &mut self.budget_form {
+      match key_code {
+        KeyCode::Esc => self.budget_form = None,
+        KeyCode::Enter => self.submit_budget_form(),
+        _ => form.process_key_event(key_code),
+      }
+      return;
+    }
+
+    if let Some(form) = &mut self.recurring_form {
+      match key_code {
+        KeyCode::Esc => self.recurring_form = None,
+        KeyCode::Enter => self.submit_recurring_form(),
+        _ => form.process_key_event(key_code),
+      }
+      return;
+    }
+
+    if self.recurring.prompt.is_some() {
+      match key_code {
+        KeyCode::Char('y') | KeyCode::Enter => self.generate_recurring(),
+        KeyCode::Char('s') => self.skip_recurring(),
+        KeyCode::Char('n') | KeyCode::Esc => self.recurring.dismiss(),
+        _ => (),
+      }
+      return;
+    }
+
+    if let Some(form) = &mut self.category_form {
+      match key_code {
+        KeyCode::Esc => self.category_form = None,
+        KeyCode::Enter => self.submit_category_form(),
+        _ => form.process_key_event(key_code),
+      }
+      return;
+    }
+
+    if self.import.is_some() {
+      let known = self.known_transactions();
+
+      if let Some(import) = &mut self.import {
+        match key_code {
+          KeyCode::Esc => {
+            if !import.back() {
+              self.import = None;
+            }
           }
+          KeyCode::Enter if import.step == ImportStep::Preview => self.submit_import(),
+          _ => import.process_key_event(key_code, &known),
         }
-        SelectedBlock::Incomes => {
-          if let Some(i) = self.table_state.incomes.state.selected() {
-            self.details_shown = true;
-            self.table_state.transactions_details = Some(self.table_state.row_incomes[i].clone());
+      }
+      return;
+    }
+
+    if let Some(export) = &mut self.export {
+      match key_code {
+        KeyCode::Esc => self.export = None,
+        KeyCode::Enter => self.submit_export(),
+        _ => export.process_key_event(key_code),
+      }
+      return;
+    }
+
+    if let Some(overview) = &mut self.year_overview {
+      match key_code {
+        KeyCode::Esc => self.year_overview = None,
+        KeyCode::Enter => self.open_overview_month(),
+        KeyCode::Down | KeyCode::Char('j') => overview.next(),
+        KeyCode::Up | KeyCode::Char('k') => overview.previous(),
+        _ => (),
+      }
+      return;
+    }
+
+    if let Some(input) = &mut self.search {
+      match key_code {
+        KeyCode::Esc => {
+          self.search = None;
+          self.filter.text.clear();
+          self.update_transaction_rows();
+        }
+        KeyCode::Enter => self.search = None,
+        _ => {
+          if let Some(request) = to_input_request(key_code) {
+            input.handle(request);
+            self.filter.text = input.value().to_string();
+            self.update_transaction_rows();
           }
         }
-        SelectedBlock::Expenses => {
-          if let Some(i) = self.table_state.expenses.state.selected() {
+      }
+      return;
+    }
+
+    if let Some(form) = &mut self.filter_form {
+      match key_code {
+        KeyCode::Esc => self.filter_form = None,
+        KeyCode::Enter => self.submit_filter_form(),
+        _ => form.process_key_event(key_code),
+      }
+      return;
+    }
+
+    if self.delete_confirmation.is_some() {
+      match key_code {
+        KeyCode::Char('y') | KeyCode::Enter => self.delete_target(),
+        KeyCode::Char('n') | KeyCode::Esc => self.delete_confirmation = None,
+        _ => (),
+      }
+      return;
+    }
+
+    match key_code {
+      KeyCode::Char('j') if self.is_navigation => self.select_block(true),
+      KeyCode::Char('k') if self.is_navigation => self.select_block(false),
+      KeyCode::Enter => match self.selected_block {
+        // The All tab has no room for the details pane, so there is nothing to edit or delete.
+        SelectedBlock::Savings | SelectedBlock::Incomes | SelectedBlock::Expenses
+          if self.tabs.current() != Tab::All =>
+        {
+          if let Some(transaction) = self.table_state.selected(&self.selected_block) {
+            self.table_state.transactions_details = Some(transaction.clone());
             self.details_shown = true;
-            self.table_state.transactions_details = Some(self.table_state.row_expenses[i].clone());
           }
         }
         SelectedBlock::Months => {
+          if let Some(year) = self.tree.selected_year(&self.months_by_year) {
+            self.tree.state.toggle_selected();
+            return self.open_year_overview(year);
+          }
+
           self.tree.toggle(&self.months_by_year);
-          self.set_transactions().await;
+          self.refresh();
         }
+        SelectedBlock::Budgets => self.open_budget_form(),
+        SelectedBlock::Recurring => self.open_recurring_form(),
         _ => (),
       },
       KeyCode::Left => {
@@ -308,7 +1312,8 @@ impl<'a> App<'a> {
         if self.selected_block == SelectedBlock::Tabs {
           self.details_shown = false;
           self.table_state.transactions_details = None;
-          self.tabs.previouse()
+          self.tabs.previouse();
+          self.on_tab_changed();
         }
       }
       KeyCode::Right => {
@@ -319,7 +1324,8 @@ impl<'a> App<'a> {
         if self.selected_block == SelectedBlock::Tabs {
           self.details_shown = false;
           self.table_state.transactions_details = None;
-          self.tabs.next()
+          self.tabs.next();
+          self.on_tab_changed();
         }
       }
       KeyCode::Down => match self.selected_block {
@@ -327,6 +1333,10 @@ impl<'a> App<'a> {
         SelectedBlock::Incomes | SelectedBlock::Expenses | SelectedBlock::Savings => {
           self.table_state.next(&self.selected_block);
         }
+        SelectedBlock::Categories => self.categories_table.next(),
+        SelectedBlock::Budgets => self.budgets.next(),
+        SelectedBlock::Installments => self.installments.next(),
+        SelectedBlock::Recurring => self.recurring.next(),
         _ => (),
       },
       KeyCode::Up => match self.selected_block {
@@ -334,10 +1344,91 @@ impl<'a> App<'a> {
         SelectedBlock::Incomes | SelectedBlock::Expenses | SelectedBlock::Savings => {
           self.table_state.previous(&self.selected_block);
         }
+        SelectedBlock::Categories => self.categories_table.previous(),
+        SelectedBlock::Budgets => self.budgets.previous(),
+        SelectedBlock::Installments => self.installments.previous(),
+        SelectedBlock::Recurring => self.recurring.previous(),
         _ => (),
       },
-      KeyCode::Char('r') => {
-        self.set_transactions().await;
+      KeyCode::Char('r') if self.offline => {
+        self.check_health();
+        self.refresh();
+      }
+      KeyCode::Char('r') if matches!(self.tabs.current(), Tab::Charts | Tab::Installments) => {
+        self.refresh();
+        self.invalidate_history();
+      }
+      KeyCode::Char('r') => self.refresh(),
+      KeyCode::Char('[') => {
+        self.tree.current = self.tree.current.previous();
+        self.refresh();
+      }
+      KeyCode::Char(']') => {
+        self.tree.current = self.tree.current.next();
+        self.refresh();
+      }
+      KeyCode::Esc if self.notifications.current().is_none() && !self.filter.is_empty() => {
+        self.set_filter(TransactionFilter::default())
+      }
+      KeyCode::Esc => self.notifications.dismiss(),
+      KeyCode::Char('/') => self.search = Some(Input::new(self.filter.text.clone())),
+      KeyCode::Char('f') => self.filter_form = Some(FilterFormState::new(&self.filter)),
+      KeyCode::Char('a') | KeyCode::Char('e') | KeyCode::Char('d')
+        if self.offline
+          && (self.tabs.current() == Tab::Categories
+            || self.selected_block == SelectedBlock::Categories) =>
+      {
+        self
+          .notifications
+          .warn("Categories are read-only while offline");
+      }
+      KeyCode::Char('d') if self.offline && self.details_shown => {
+        self
+          .notifications
+          .warn("Deleting is not available while offline");
+      }
+      KeyCode::Char('a') | KeyCode::Char('e') if self.selected_block == SelectedBlock::Budgets => {
+        self.open_budget_form()
+      }
+      KeyCode::Char('d') if self.selected_block == SelectedBlock::Budgets => self.clear_budget(),
+      KeyCode::Char('a') | KeyCode::Char('e')
+        if self.selected_block == SelectedBlock::Recurring =>
+      {
+        self.open_recurring_form()
+      }
+      KeyCode::Char('a') if self.tabs.current() == Tab::Recurring => self.open_recurring_form(),
+      KeyCode::Char('d') if self.selected_block == SelectedBlock::Recurring => {
+        self.delete_confirmation = self
+          .recurring
+          .selected_template()
+          .cloned()
+          .map(|template| DeleteTarget::Recurring(Box::new(template)));
+      }
+      KeyCode::Char('c') => self.cycle_display_currency(),
+      KeyCode::Char('i') => self.import = Some(ImportState::new()),
+      KeyCode::Char('x') => self.export = Some(ExportState::new(self.tree.current.clone())),
+      KeyCode::Char('a') if self.tabs.current() == Tab::Categories => self.open_category_form(),
+      KeyCode::Char('a') => self.open_transaction_form(),
+      KeyCode::Char('e') if self.selected_block == SelectedBlock::Categories => {
+        self.open_edit_category_form()
+      }
+      KeyCode::Char('e') if self.details_shown => self.open_edit_transaction_form(),
+      KeyCode::Char('d') if self.selected_block == SelectedBlock::Categories => {
+        self.delete_confirmation = self
+          .selected_category()
+          .cloned()
+          .map(|category| DeleteTarget::Category(Box::new(category)));
+
+        if self.delete_confirmation.is_none() {
+          self.notifications.warn("Select a category first");
+        }
+      }
+      KeyCode::Char('d') if self.details_shown => {
+        self.delete_confirmation = self
+          .table_state
+          .transactions_details
+          .clone()
+          .map(|transaction| DeleteTarget::Transaction(Box::new(transaction)));
       }
       _ => {}
     }
@@ -0,0 +1,84 @@
+use std::{collections::VecDeque, time::Instant};
+
+use chrono::{DateTime, Local};
+
+use crate::enums::notification_level::NotificationLevel;
+
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+  pub level: NotificationLevel,
+  pub message: String,
+  pub created_at: Instant,
+}
+
+impl Notification {
+  fn is_expired(&self) -> bool {
+    self.created_at.elapsed() >= self.level.duration()
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct NotificationsState {
+  pub queue: VecDeque<Notification>,
+  pub last_refresh: Option<DateTime<Local>>,
+  spinner_index: usize,
+}
+
+impl NotificationsState {
+  pub fn push(&mut self, level: NotificationLevel, message: String) {
+    self.queue.push_back(Notification {
+      level,
+      message,
+      created_at: Instant::now(),
+    });
+  }
+
+  pub fn info(&mut self, message: &str) {
+    self.push(NotificationLevel::Info, message.to_string());
+  }
+
+  pub fn warn(&mut self, message: &str) {
+    self.push(NotificationLevel::Warn, message.to_string());
+  }
+
+  pub fn error(&mut self, message: String) {
+    self.push(NotificationLevel::Error, message);
+  }
+
+  /// The notification currently on screen, older ones are shown first.
+  pub fn current(&self) -> Option<&Notification> {
+    self.queue.front()
+  }
+
+  pub fn dismiss(&mut self) {
+    self.queue.pop_front();
+  }
+
+  /// Drops the current notification once its timer ran out and advances the spinner.
+  pub fn tick(&mut self) {
+    if self
+      .queue
+      .front()
+      .map(|notification| notification.is_expired())
+      .unwrap_or(false)
+    {
+      self.queue.pop_front();
+
+      if let Some(next) = self.queue.front_mut() {
+        next.created_at = Instant::now();
+      }
+    }
+
+    self.spinner_index = (self.spinner_index + 1) % SPINNER_FRAMES.len();
+  }
+
+  pub fn spinner(&self) -> &'static str {
+    SPINNER_FRAMES[self.spinner_index]
+  }
+
+  pub fn refreshed(&mut self, at: DateTime<Local>) {
+    self.last_refresh = Some(at);
+  }
+}
@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use tui::widgets::TableState;
+
+use crate::{
+  types::year_month::YearMonth,
+  utils::recurring::{Occurrence, Recurring, RecurringTemplate},
+};
+
+/// Occurrences of a month offered to be created when the month is opened.
+pub struct RecurringPrompt {
+  pub period: YearMonth,
+  pub occurrences: Vec<Occurrence>,
+}
+
+pub struct RecurringState {
+  pub store: Recurring,
+  pub table: TableState,
+  pub prompt: Option<RecurringPrompt>,
+  /// Occurrences being created, marked as generated once the server confirms them.
+  pub generating: Vec<Occurrence>,
+  /// Months whose prompt was postponed, not asked again until the app restarts.
+  dismissed: HashSet<YearMonth>,
+}
+
+impl RecurringState {
+  pub fn new(store: Recurring) -> Self {
+    Self {
+      store,
+      table: TableState::default(),
+      prompt: None,
+      generating: Vec::new(),
+      dismissed: HashSet::new(),
+    }
+  }
+
+  pub fn templates(&self) -> &[RecurringTemplate] {
+    &self.store.data.templates
+  }
+
+  pub fn selected_template(&self) -> Option<&RecurringTemplate> {
+    self.templates().get(self.table.selected()?)
+  }
+
+  pub fn next(&mut self) {
+    let len = self.templates().len();
+    if len == 0 {
+      return;
+    }
+
+    let i = self.table.selected().map_or(0, |i| (i + 1) % len);
+    self.table.select(Some(i));
+  }
+
+  pub fn previous(&mut self) {
+    let len = self.templates().len();
+    if len == 0 {
+      return;
+    }
+
+    let i = self.table.selected().map_or(0, |i| (i + len - 1) % len);
+    self.table.select(Some(i));
+  }
+
+  pub fn clamp_selection(&mut self) {
+    let len = self.templates().len();
+
+    match self.table.selected() {
+      Some(_) if len == 0 => self.table.select(None),
+      Some(i) if i >= len => self.table.select(Some(len - 1)),
+      _ => (),
+    }
+  }
+
+  /// Prompts for the pending occurrences of `period`, future months are left
+  /// alone until they arrive.
+  pub fn check(&mut self, period: &YearMonth) {
+    if self.prompt.is_some()
+      || !self.generating.is_empty()
+      || *period > YearMonth::now()
+      || self.dismissed.contains(period)
+    {
+      return;
+    }
+
+    let occurrences = self.store.pending(period);
+
+    if !occurrences.is_empty() {
+      self.prompt = Some(RecurringPrompt {
+        period: period.clone(),
+        occurrences,
+      });
+    }
+  }
+
+  pub fn dismiss(&mut self) {
+    if let Some(prompt) = self.prompt.take() {
+      self.dismissed.insert(prompt.period);
+    }
+  }
+}
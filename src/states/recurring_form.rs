@@ -0,0 +1,242 @@
+use std::collections::BTreeSet;
+
+use chrono::{Local, NaiveDate};
+use crossterm::event::KeyCode;
+use rust_decimal::Decimal;
+use tui_input::Input;
+
+use crate::{
+  enums::{
+    currency::CurrencyEnum, frequency::Frequency, recurring_form_field::RecurringFormField,
+    transaction_type::TransactionType,
+  },
+  models::category::CategoryModel,
+  storage::local::new_id,
+  utils::{input::to_input_request, recurring::RecurringTemplate},
+};
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+pub struct RecurringFormState {
+  pub template_id: Option<String>,
+  pub transaction_type: TransactionType,
+  pub amount: Input,
+  pub currency: CurrencyEnum,
+  pub categories: Vec<CategoryModel>,
+  pub category: Option<usize>,
+  pub frequency: Frequency,
+  pub start: Input,
+  pub end: Input,
+  pub note: Input,
+  pub selected_field: RecurringFormField,
+  pub error: Option<String>,
+  generated: BTreeSet<NaiveDate>,
+}
+
+impl RecurringFormState {
+  pub fn new(categories: Vec<CategoryModel>) -> Self {
+    Self {
+      template_id: None,
+      transaction_type: TransactionType::EXPENSE,
+      amount: Input::default(),
+      currency: CurrencyEnum::UYU,
+      categories,
+      category: None,
+      frequency: Frequency::MONTHLY,
+      start: Input::new(Local::now().date_naive().format(DATE_FORMAT).to_string()),
+      end: Input::default(),
+      note: Input::default(),
+      selected_field: RecurringFormField::Type,
+      error: None,
+      generated: BTreeSet::new(),
+    }
+  }
+
+  pub fn from_template(template: &RecurringTemplate, categories: Vec<CategoryModel>) -> Self {
+    let mut form = Self {
+      template_id: Some(template.id.clone()),
+      transaction_type: template.r#type.clone(),
+      amount: Input::new(template.amount.to_string()),
+      currency: template.currency.clone(),
+      categories,
+      category: None,
+      frequency: template.frequency.clone(),
+      start: Input::new(template.start.format(DATE_FORMAT).to_string()),
+      end: match template.end {
+        Some(end) => Input::new(end.format(DATE_FORMAT).to_string()),
+        None => Input::default(),
+      },
+      note: Input::new(template.note.clone().unwrap_or_default()),
+      selected_field: RecurringFormField::Type,
+      error: None,
+      generated: template.generated.clone(),
+    };
+
+    form.category = form
+      .filtered_categories()
+      .iter()
+      .position(|category| Some(&category.category_id) == template.category_id.as_ref());
+
+    form
+  }
+
+  pub fn filtered_categories(&self) -> Vec<&CategoryModel> {
+    self
+      .categories
+      .iter()
+      .filter(|category| category.r#type == self.transaction_type)
+      .collect()
+  }
+
+  pub fn selected_category(&self) -> Option<&CategoryModel> {
+    self
+      .category
+      .and_then(|index| self.filtered_categories().get(index).copied())
+  }
+
+  pub fn selected_input(&self) -> Option<&Input> {
+    match self.selected_field {
+      RecurringFormField::Amount => Some(&self.amount),
+      RecurringFormField::Start => Some(&self.start),
+      RecurringFormField::End => Some(&self.end),
+      RecurringFormField::Note => Some(&self.note),
+      _ => None,
+    }
+  }
+
+  fn selected_input_mut(&mut self) -> Option<&mut Input> {
+    match self.selected_field {
+      RecurringFormField::Amount => Some(&mut self.amount),
+      RecurringFormField::Start => Some(&mut self.start),
+      RecurringFormField::End => Some(&mut self.end),
+      RecurringFormField::Note => Some(&mut self.note),
+      _ => None,
+    }
+  }
+
+  fn select_next_field(&mut self) {
+    self.selected_field = match self.selected_field {
+      RecurringFormField::Type => RecurringFormField::Amount,
+      RecurringFormField::Amount => RecurringFormField::Currency,
+      RecurringFormField::Currency => RecurringFormField::Category,
+      RecurringFormField::Category => RecurringFormField::Frequency,
+      RecurringFormField::Frequency => RecurringFormField::Start,
+      RecurringFormField::Start => RecurringFormField::End,
+      RecurringFormField::End => RecurringFormField::Note,
+      RecurringFormField::Note => RecurringFormField::Type,
+    };
+  }
+
+  fn select_previous_field(&mut self) {
+    self.selected_field = match self.selected_field {
+      RecurringFormField::Type => RecurringFormField::Note,
+      RecurringFormField::Amount => RecurringFormField::Type,
+      RecurringFormField::Currency => RecurringFormField::Amount,
+      RecurringFormField::Category => RecurringFormField::Currency,
+      RecurringFormField::Frequency => RecurringFormField::Category,
+      RecurringFormField::Start => RecurringFormField::Frequency,
+      RecurringFormField::End => RecurringFormField::Start,
+      RecurringFormField::Note => RecurringFormField::End,
+    };
+  }
+
+  fn select_next_option(&mut self) {
+    match self.selected_field {
+      RecurringFormField::Type => {
+        self.transaction_type = self.transaction_type.next();
+        self.category = None;
+      }
+      RecurringFormField::Currency => self.currency = self.currency.next(),
+      RecurringFormField::Frequency => self.frequency = self.frequency.next(),
+      RecurringFormField::Category => {
+        let len = self.filtered_categories().len();
+        self.category = match self.category {
+          _ if len == 0 => None,
+          Some(i) if i + 1 < len => Some(i + 1),
+          Some(_) => None,
+          None => Some(0),
+        };
+      }
+      _ => (),
+    }
+  }
+
+  fn select_previous_option(&mut self) {
+    match self.selected_field {
+      RecurringFormField::Type => {
+        self.transaction_type = self.transaction_type.previous();
+        self.category = None;
+      }
+      RecurringFormField::Currency => self.currency = self.currency.previous(),
+      RecurringFormField::Frequency => self.frequency = self.frequency.previous(),
+      RecurringFormField::Category => {
+        let len = self.filtered_categories().len();
+        self.category = match self.category {
+          _ if len == 0 => None,
+          Some(0) => None,
+          Some(i) => Some(i - 1),
+          None => Some(len - 1),
+        };
+      }
+      _ => (),
+    }
+  }
+
+  pub fn to_template(&self) -> Result<RecurringTemplate, String> {
+    let amount = match self.amount.value().trim().parse::<Decimal>() {
+      Ok(amount) if amount > Decimal::ZERO => amount,
+      _ => return Err(String::from("Amount must be a positive number")),
+    };
+
+    let start = NaiveDate::parse_from_str(self.start.value().trim(), DATE_FORMAT)
+      .map_err(|_| String::from("Start must be a date like 2023-01-31"))?;
+
+    let end = match self.end.value().trim() {
+      "" => None,
+      value => match NaiveDate::parse_from_str(value, DATE_FORMAT) {
+        Ok(end) if end >= start => Some(end),
+        Ok(_) => return Err(String::from("End must be after the start")),
+        Err(_) => return Err(String::from("End must be a date like 2023-12-31")),
+      },
+    };
+
+    let note = match self.note.value().trim() {
+      "" => None,
+      note => Some(note.to_string()),
+    };
+
+    Ok(RecurringTemplate {
+      id: self.template_id.clone().unwrap_or_else(new_id),
+      r#type: self.transaction_type.clone(),
+      amount,
+      currency: self.currency.clone(),
+      category_id: self
+        .selected_category()
+        .map(|category| category.category_id.clone()),
+      note,
+      frequency: self.frequency.clone(),
+      start,
+      end,
+      generated: self.generated.clone(),
+    })
+  }
+
+  pub fn process_key_event(&mut self, key_code: KeyCode) {
+    match key_code {
+      KeyCode::Tab | KeyCode::Down => self.select_next_field(),
+      KeyCode::BackTab | KeyCode::Up => self.select_previous_field(),
+      _ => match self.selected_input_mut() {
+        Some(input) => {
+          if let Some(request) = to_input_request(key_code) {
+            input.handle(request);
+          }
+        }
+        None => match key_code {
+          KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => self.select_next_option(),
+          KeyCode::Left | KeyCode::Char('h') => self.select_previous_option(),
+          _ => (),
+        },
+      },
+    }
+  }
+}
@@ -0,0 +1,145 @@
+use crossterm::event::KeyCode;
+use tui_input::Input;
+
+use crate::{
+  requests::api_client::ApiClient,
+  enums::{language::Language, sign_up_field::SignUpField},
+  models::user::UserModel,
+  requests::user,
+  types::request::user::CreateUserRequest,
+  utils::input::to_input_request,
+};
+
+const MIN_PASSWORD_LENGTH: usize = 8;
+
+pub struct SignUpState {
+  pub email: Input,
+  pub first_name: Input,
+  pub last_name: Input,
+  pub password: Input,
+  pub confirm_password: Input,
+  pub selected_field: SignUpField,
+  pub error: Option<String>,
+  pub user: Option<UserModel>,
+  pub api: ApiClient,
+  pub language: Language,
+}
+
+impl SignUpState {
+  pub fn new(api: ApiClient, language: Language) -> Self {
+    Self {
+      email: Input::default(),
+      first_name: Input::default(),
+      last_name: Input::default(),
+      password: Input::default(),
+      confirm_password: Input::default(),
+      selected_field: SignUpField::Email,
+      error: None,
+      user: None,
+      api,
+      language,
+    }
+  }
+
+  fn selected_input(&mut self) -> &mut Input {
+    match self.selected_field {
+      SignUpField::Email => &mut self.email,
+      SignUpField::FirstName => &mut self.first_name,
+      SignUpField::LastName => &mut self.last_name,
+      SignUpField::Password => &mut self.password,
+      SignUpField::ConfirmPassword => &mut self.confirm_password,
+    }
+  }
+
+  fn select_next_field(&mut self) {
+    self.selected_field = match self.selected_field {
+      SignUpField::Email => SignUpField::FirstName,
+      SignUpField::FirstName => SignUpField::LastName,
+      SignUpField::LastName => SignUpField::Password,
+      SignUpField::Password => SignUpField::ConfirmPassword,
+      SignUpField::ConfirmPassword => SignUpField::Email,
+    };
+  }
+
+  fn select_previous_field(&mut self) {
+    self.selected_field = match self.selected_field {
+      SignUpField::Email => SignUpField::ConfirmPassword,
+      SignUpField::FirstName => SignUpField::Email,
+      SignUpField::LastName => SignUpField::FirstName,
+      SignUpField::Password => SignUpField::LastName,
+      SignUpField::ConfirmPassword => SignUpField::Password,
+    };
+  }
+
+  fn validate(&self) -> Result<CreateUserRequest, String> {
+    let email = self.email.value().trim();
+    let first_name = self.first_name.value().trim();
+    let last_name = self.last_name.value().trim();
+    let password = self.password.value();
+
+    let is_valid_email = match email.split_once('@') {
+      Some((user, domain)) => !user.is_empty() && domain.contains('.') && !domain.starts_with('.'),
+      None => false,
+    };
+
+    if !is_valid_email {
+      return Err(String::from("Invalid email"));
+    }
+
+    if first_name.is_empty() || last_name.is_empty() {
+      return Err(String::from("First and last name are required"));
+    }
+
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+      return Err(format!(
+        "Password must have at least {} characters",
+        MIN_PASSWORD_LENGTH
+      ));
+    }
+
+    if password != self.confirm_password.value() {
+      return Err(String::from("Passwords do not match"));
+    }
+
+    Ok(CreateUserRequest {
+      email: email.to_string(),
+      first_name: first_name.to_string(),
+      last_name: last_name.to_string(),
+      password: password.to_string(),
+    })
+  }
+
+  pub async fn sign_up(&mut self) {
+    let new_user = match self.validate() {
+      Ok(new_user) => new_user,
+      Err(err) => {
+        self.error = Some(err);
+        return;
+      }
+    };
+
+    match user::create_user(&self.api, new_user).await {
+      Ok(user) => {
+        self.error = None;
+        self.user = Some(user);
+      }
+      Err(err) => self.error = Some(err.message(&self.language)),
+    }
+  }
+
+  pub async fn process_key_event(&mut self, key_code: KeyCode) {
+    match key_code {
+      KeyCode::Tab | KeyCode::Down => self.select_next_field(),
+      KeyCode::BackTab | KeyCode::Up => self.select_previous_field(),
+      KeyCode::Enter => match self.selected_field {
+        SignUpField::ConfirmPassword => self.sign_up().await,
+        _ => self.select_next_field(),
+      },
+      _ => {
+        if let Some(request) = to_input_request(key_code) {
+          self.selected_input().handle(request);
+        }
+      }
+    }
+  }
+}
@@ -12,6 +12,60 @@ pub enum SortMode {
 pub struct CustomTableState {
   pub state: TableState,
   pub items: Vec<Vec<String>>,
+  /// Index of each row in the data it was built from, rows may be filtered out.
+  pub source: Vec<usize>,
+}
+
+impl CustomTableState {
+  pub fn new(items: Vec<Vec<String>>) -> Self {
+    Self {
+      state: TableState::default(),
+      source: (0..items.len()).collect(),
+      items,
+    }
+  }
+
+  pub fn next(&mut self) {
+    if self.items.is_empty() {
+      return;
+    }
+
+    self.state.select(match self.state.selected() {
+      Some(i) => {
+        if i >= self.items.len() - 1 {
+          Some(0)
+        } else {
+          Some(i + 1)
+        }
+      }
+      None => Some(0),
+    });
+  }
+
+  pub fn previous(&mut self) {
+    if self.items.is_empty() {
+      return;
+    }
+
+    self.state.select(match self.state.selected() {
+      Some(i) => {
+        if i == 0 {
+          Some(self.items.len() - 1)
+        } else {
+          Some(i - 1)
+        }
+      }
+      None => Some(0),
+    });
+  }
+
+  pub fn clamp_selection(&mut self) {
+    match self.state.selected() {
+      Some(_) if self.items.is_empty() => self.state.select(None),
+      Some(i) if i >= self.items.len() => self.state.select(Some(self.items.len() - 1)),
+      _ => (),
+    }
+  }
 }
 
 pub struct TransactionsTableState {
@@ -44,92 +98,31 @@ impl TransactionsTableState {
     }
   }
 
+  pub fn selected(&self, selected_block: &SelectedBlock) -> Option<&TransactionModel> {
+    let (table, rows) = match selected_block {
+      SelectedBlock::Incomes => (&self.incomes, &self.row_incomes),
+      SelectedBlock::Savings => (&self.savings, &self.row_savings),
+      SelectedBlock::Expenses => (&self.expenses, &self.row_expenses),
+      _ => return None,
+    };
+
+    rows.get(*table.source.get(table.state.selected()?)?)
+  }
+
   pub fn next(&mut self, selected_block: &SelectedBlock) {
     match selected_block {
-      SelectedBlock::Incomes => self
-        .incomes
-        .state
-        .select(match self.incomes.state.selected() {
-          Some(i) => {
-            if i >= self.incomes.items.len() - 1 {
-              Some(0)
-            } else {
-              Some(i + 1)
-            }
-          }
-          None => Some(0),
-        }),
-      SelectedBlock::Savings => self
-        .savings
-        .state
-        .select(match self.savings.state.selected() {
-          Some(i) => {
-            if i >= self.savings.items.len() - 1 {
-              Some(0)
-            } else {
-              Some(i + 1)
-            }
-          }
-          None => Some(0),
-        }),
-      SelectedBlock::Expenses => self
-        .expenses
-        .state
-        .select(match self.expenses.state.selected() {
-          Some(i) => {
-            if i >= self.expenses.items.len() - 1 {
-              Some(0)
-            } else {
-              Some(i + 1)
-            }
-          }
-          None => Some(0),
-        }),
+      SelectedBlock::Incomes => self.incomes.next(),
+      SelectedBlock::Savings => self.savings.next(),
+      SelectedBlock::Expenses => self.expenses.next(),
       _ => {}
     };
   }
 
   pub fn previous(&mut self, selected_block: &SelectedBlock) {
     match selected_block {
-      SelectedBlock::Incomes => self
-        .incomes
-        .state
-        .select(match self.incomes.state.selected() {
-          Some(i) => {
-            if i == 0 {
-              Some(self.incomes.items.len() - 1)
-            } else {
-              Some(i - 1)
-            }
-          }
-          None => Some(0),
-        }),
-      SelectedBlock::Savings => self
-        .savings
-        .state
-        .select(match self.savings.state.selected() {
-          Some(i) => {
-            if i == 0 {
-              Some(self.savings.items.len() - 1)
-            } else {
-              Some(i - 1)
-            }
-          }
-          None => Some(0),
-        }),
-      SelectedBlock::Expenses => self
-        .expenses
-        .state
-        .select(match self.expenses.state.selected() {
-          Some(i) => {
-            if i == 0 {
-              Some(self.expenses.items.len() - 1)
-            } else {
-              Some(i - 1)
-            }
-          }
-          None => Some(0),
-        }),
+      SelectedBlock::Incomes => self.incomes.previous(),
+      SelectedBlock::Savings => self.savings.previous(),
+      SelectedBlock::Expenses => self.expenses.previous(),
       _ => {}
     };
   }
@@ -1,3 +1,5 @@
+use crate::enums::tab::Tab;
+
 #[derive(Debug)]
 pub struct TabsState<'a> {
   pub titles: Vec<&'a str>,
@@ -7,7 +9,7 @@ pub struct TabsState<'a> {
 impl<'a> Default for TabsState<'a> {
   fn default() -> Self {
     TabsState {
-      titles: vec!["All", "Expenses", "Incomes", "Savings"],
+      titles: Tab::ALL.iter().map(Tab::title).collect(),
       index: 0,
     }
   }
@@ -18,6 +20,11 @@ impl<'a> TabsState<'a> {
     Self { titles, index }
   }
 
+  /// The selected tab, `All` for an index past the known tabs.
+  pub fn current(&self) -> Tab {
+    Tab::ALL.get(self.index).copied().unwrap_or(Tab::All)
+  }
+
   pub fn next(&mut self) {
     self.index = (self.index + 1) % self.titles.len();
   }
@@ -0,0 +1,300 @@
+use chrono::{Datelike, Local};
+use crossterm::event::KeyCode;
+use rust_decimal::Decimal;
+use tui_input::Input;
+
+use crate::{
+  enums::{
+    currency::CurrencyEnum, month::MonthEnum, transaction_form_field::TransactionFormField,
+    transaction_type::TransactionType,
+  },
+  models::{category::CategoryModel, transaction::TransactionModel},
+  types::{request::transaction::CreateTransactionRequest, year_month::YearMonth},
+  utils::input::to_input_request,
+};
+
+pub struct TransactionFormState {
+  pub transaction_id: Option<String>,
+  pub transaction_type: TransactionType,
+  pub amount: Input,
+  pub currency: CurrencyEnum,
+  pub exchange_rate: Input,
+  pub categories: Vec<CategoryModel>,
+  pub category: Option<usize>,
+  pub day: Input,
+  pub month: MonthEnum,
+  pub year: Input,
+  pub note: Input,
+  pub selected_field: TransactionFormField,
+  pub error: Option<String>,
+  /// Set while the request is in flight, further submits are ignored.
+  pub saving: bool,
+}
+
+impl TransactionFormState {
+  pub fn new(
+    transaction_type: TransactionType,
+    period: &YearMonth,
+    categories: Vec<CategoryModel>,
+  ) -> Self {
+    let now = Local::now();
+
+    let day = if YearMonth::now() == *period {
+      Input::new(now.day().to_string())
+    } else {
+      Input::default()
+    };
+
+    Self {
+      transaction_id: None,
+      transaction_type,
+      amount: Input::default(),
+      currency: CurrencyEnum::UYU,
+      exchange_rate: Input::default(),
+      categories,
+      category: None,
+      day,
+      month: period.month.clone(),
+      year: Input::new(period.year.to_string()),
+      note: Input::default(),
+      selected_field: TransactionFormField::Type,
+      error: None,
+      saving: false,
+    }
+  }
+
+  pub fn from_transaction(
+    transaction: &TransactionModel,
+    mut categories: Vec<CategoryModel>,
+  ) -> Self {
+    if let Some(category) = &transaction.category {
+      if !categories
+        .iter()
+        .any(|known| known.category_id == category.category_id)
+      {
+        categories.push(category.clone());
+      }
+    }
+
+    let mut form = Self {
+      transaction_id: Some(transaction.transaction_id.clone()),
+      transaction_type: transaction.r#type.clone(),
+      amount: Input::new(transaction.amount.to_string()),
+      currency: transaction.currency.clone(),
+      exchange_rate: match transaction.exchange_rate {
+        Some(rate) => Input::new(rate.to_string()),
+        None => Input::default(),
+      },
+      categories,
+      category: None,
+      day: match transaction.day {
+        Some(day) => Input::new(day.to_string()),
+        None => Input::default(),
+      },
+      month: transaction.month.clone(),
+      year: Input::new(transaction.year.to_string()),
+      note: Input::new(transaction.note.clone().unwrap_or_default()),
+      selected_field: TransactionFormField::Type,
+      error: None,
+      saving: false,
+    };
+
+    form.category = form
+      .filtered_categories()
+      .iter()
+      .position(|category| category.category_id == transaction.category_id);
+
+    form
+  }
+
+  pub fn filtered_categories(&self) -> Vec<&CategoryModel> {
+    self
+      .categories
+      .iter()
+      .filter(|category| category.r#type == self.transaction_type)
+      .collect()
+  }
+
+  pub fn selected_category(&self) -> Option<&CategoryModel> {
+    self
+      .category
+      .and_then(|index| self.filtered_categories().get(index).copied())
+  }
+
+  pub fn selected_input(&self) -> Option<&Input> {
+    match self.selected_field {
+      TransactionFormField::Amount => Some(&self.amount),
+      TransactionFormField::ExchangeRate => Some(&self.exchange_rate),
+      TransactionFormField::Day => Some(&self.day),
+      TransactionFormField::Year => Some(&self.year),
+      TransactionFormField::Note => Some(&self.note),
+      _ => None,
+    }
+  }
+
+  fn selected_input_mut(&mut self) -> Option<&mut Input> {
+    match self.selected_field {
+      TransactionFormField::Amount => Some(&mut self.amount),
+      TransactionFormField::ExchangeRate => Some(&mut self.exchange_rate),
+      TransactionFormField::Day => Some(&mut self.day),
+      TransactionFormField::Year => Some(&mut self.year),
+      TransactionFormField::Note => Some(&mut self.note),
+      _ => None,
+    }
+  }
+
+  fn select_next_field(&mut self) {
+    self.selected_field = match self.selected_field {
+      TransactionFormField::Type => TransactionFormField::Amount,
+      TransactionFormField::Amount => TransactionFormField::Currency,
+      TransactionFormField::Currency => {
+        if self.currency.has_exchange_rate() {
+          TransactionFormField::ExchangeRate
+        } else {
+          TransactionFormField::Category
+        }
+      }
+      TransactionFormField::ExchangeRate => TransactionFormField::Category,
+      TransactionFormField::Category => TransactionFormField::Day,
+      TransactionFormField::Day => TransactionFormField::Month,
+      TransactionFormField::Month => TransactionFormField::Year,
+      TransactionFormField::Year => TransactionFormField::Note,
+      TransactionFormField::Note => TransactionFormField::Type,
+    };
+  }
+
+  fn select_previous_field(&mut self) {
+    self.selected_field = match self.selected_field {
+      TransactionFormField::Type => TransactionFormField::Note,
+      TransactionFormField::Amount => TransactionFormField::Type,
+      TransactionFormField::Currency => TransactionFormField::Amount,
+      TransactionFormField::ExchangeRate => TransactionFormField::Currency,
+      TransactionFormField::Category => {
+        if self.currency.has_exchange_rate() {
+          TransactionFormField::ExchangeRate
+        } else {
+          TransactionFormField::Currency
+        }
+      }
+      TransactionFormField::Day => TransactionFormField::Category,
+      TransactionFormField::Month => TransactionFormField::Day,
+      TransactionFormField::Year => TransactionFormField::Month,
+      TransactionFormField::Note => TransactionFormField::Year,
+    };
+  }
+
+  fn select_next_option(&mut self) {
+    match self.selected_field {
+      TransactionFormField::Type => {
+        self.transaction_type = self.transaction_type.next();
+        self.category = None;
+      }
+      TransactionFormField::Currency => self.currency = self.currency.next(),
+      TransactionFormField::Month => self.month = self.month.next(),
+      TransactionFormField::Category => {
+        let len = self.filtered_categories().len();
+        self.category = match self.category {
+          _ if len == 0 => None,
+          Some(i) if i + 1 < len => Some(i + 1),
+          Some(_) => None,
+          None => Some(0),
+        };
+      }
+      _ => (),
+    }
+  }
+
+  fn select_previous_option(&mut self) {
+    match self.selected_field {
+      TransactionFormField::Type => {
+        self.transaction_type = self.transaction_type.previous();
+        self.category = None;
+      }
+      TransactionFormField::Currency => self.currency = self.currency.previous(),
+      TransactionFormField::Month => self.month = self.month.previous(),
+      TransactionFormField::Category => {
+        let len = self.filtered_categories().len();
+        self.category = match self.category {
+          _ if len == 0 => None,
+          Some(0) => None,
+          Some(i) => Some(i - 1),
+          None => Some(len - 1),
+        };
+      }
+      _ => (),
+    }
+  }
+
+  pub fn to_request(&self) -> Result<CreateTransactionRequest, String> {
+    let amount = match self.amount.value().trim().parse::<Decimal>() {
+      Ok(amount) if amount > Decimal::ZERO => amount,
+      _ => return Err(String::from("Amount must be a positive number")),
+    };
+
+    let exchange_rate = if self.currency.has_exchange_rate() {
+      match self.exchange_rate.value().trim() {
+        "" => None,
+        value => match value.parse::<Decimal>() {
+          Ok(rate) if rate > Decimal::ZERO => Some(rate),
+          _ => return Err(String::from("Exchange rate must be a positive number")),
+        },
+      }
+    } else {
+      None
+    };
+
+    let year = match self.year.value().trim().parse::<u16>() {
+      Ok(year) => year,
+      Err(_) => return Err(String::from("Invalid year")),
+    };
+
+    let day = match self.day.value().trim() {
+      "" => None,
+      value => match value.parse::<u8>() {
+        Ok(day) => {
+          YearMonth::new(year as i32, self.month.clone()).validate_day(day)?;
+          Some(day)
+        }
+        Err(_) => return Err(String::from("Day must be a number")),
+      },
+    };
+
+    let note = match self.note.value().trim() {
+      "" => None,
+      note => Some(note.to_string()),
+    };
+
+    Ok(CreateTransactionRequest {
+      r#type: self.transaction_type.clone(),
+      amount,
+      currency: self.currency.clone(),
+      exchange_rate,
+      day,
+      month: self.month.clone(),
+      year,
+      note,
+      category_id: self
+        .selected_category()
+        .map(|category| category.category_id.clone()),
+    })
+  }
+
+  pub fn process_key_event(&mut self, key_code: KeyCode) {
+    match key_code {
+      KeyCode::Tab | KeyCode::Down => self.select_next_field(),
+      KeyCode::BackTab | KeyCode::Up => self.select_previous_field(),
+      _ => match self.selected_input_mut() {
+        Some(input) => {
+          if let Some(request) = to_input_request(key_code) {
+            input.handle(request);
+          }
+        }
+        None => match key_code {
+          KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => self.select_next_option(),
+          KeyCode::Left | KeyCode::Char('h') => self.select_previous_option(),
+          _ => (),
+        },
+      },
+    }
+  }
+}
@@ -0,0 +1,134 @@
+use rust_decimal::Decimal;
+use tui::widgets::TableState;
+
+use crate::types::{responses::transaction::TransactionBalances, year_month::YearMonth};
+
+/// Totals of a month in UYU, as reported by the server.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MonthTotals {
+  pub incomes: Decimal,
+  pub expenses: Decimal,
+  pub savings: Decimal,
+}
+
+impl MonthTotals {
+  pub fn net(&self) -> Decimal {
+    self.incomes - self.expenses - self.savings
+  }
+
+  fn is_empty(&self) -> bool {
+    self.incomes.is_zero() && self.expenses.is_zero() && self.savings.is_zero()
+  }
+}
+
+impl From<&TransactionBalances> for MonthTotals {
+  fn from(balances: &TransactionBalances) -> Self {
+    Self {
+      incomes: balances.incomes.total,
+      expenses: balances.expenses.total,
+      savings: balances.savings.total,
+    }
+  }
+}
+
+/// The twelve months of a year, `None` until a month's balances are known.
+pub struct YearOverviewState {
+  pub year: i32,
+  pub months: Vec<(YearMonth, Option<MonthTotals>)>,
+  pub loading: bool,
+  pub table: TableState,
+}
+
+impl YearOverviewState {
+  /// Starts with the month of `current` selected when it falls in `year`.
+  pub fn new(year: i32, current: &YearMonth) -> Self {
+    let months: Vec<(YearMonth, Option<MonthTotals>)> = YearMonth::months_of(year)
+      .into_iter()
+      .map(|period| (period, None))
+      .collect();
+
+    let selected = months
+      .iter()
+      .position(|(period, _)| period == current)
+      .unwrap_or(0);
+
+    let mut table = TableState::default();
+    table.select(Some(selected));
+
+    Self {
+      year,
+      months,
+      loading: true,
+      table,
+    }
+  }
+
+  pub fn set_balances(&mut self, balances: &[(YearMonth, TransactionBalances)]) {
+    for (period, summary) in balances {
+      if let Some((_, totals)) = self.months.iter_mut().find(|(month, _)| month == period) {
+        *totals = Some(MonthTotals::from(summary));
+      }
+    }
+
+    self.loading = false;
+  }
+
+  pub fn next(&mut self) {
+    let i = self
+      .table
+      .selected()
+      .map_or(0, |i| (i + 1) % self.months.len());
+    self.table.select(Some(i));
+  }
+
+  pub fn previous(&mut self) {
+    let len = self.months.len();
+    let i = self.table.selected().map_or(0, |i| (i + len - 1) % len);
+    self.table.select(Some(i));
+  }
+
+  pub fn selected_period(&self) -> Option<YearMonth> {
+    let (period, _) = self.months.get(self.table.selected()?)?;
+    Some(period.clone())
+  }
+
+  /// Months with any movement, the ones totals are averaged over.
+  fn active(&self) -> impl Iterator<Item = (usize, &MonthTotals)> {
+    self
+      .months
+      .iter()
+      .enumerate()
+      .filter_map(|(index, (_, totals))| totals.as_ref().map(|totals| (index, totals)))
+      .filter(|(_, totals)| !totals.is_empty())
+  }
+
+  pub fn total(&self) -> MonthTotals {
+    self
+      .active()
+      .fold(MonthTotals::default(), |mut total, (_, totals)| {
+        total.incomes += totals.incomes;
+        total.expenses += totals.expenses;
+        total.savings += totals.savings;
+        total
+      })
+  }
+
+  pub fn average(&self) -> MonthTotals {
+    let count = Decimal::from(self.active().count().max(1));
+    let total = self.total();
+
+    MonthTotals {
+      incomes: total.incomes / count,
+      expenses: total.expenses / count,
+      savings: total.savings / count,
+    }
+  }
+
+  /// Indexes of the months with the highest and lowest net.
+  pub fn best_and_worst(&self) -> Option<(usize, usize)> {
+    let best = self.active().max_by_key(|(_, totals)| totals.net())?.0;
+    let worst = self.active().min_by_key(|(_, totals)| totals.net())?.0;
+
+    Some((best, worst))
+  }
+}
@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+
+use crate::{
+  enums::transaction_type::TransactionType,
+  models::{category::CategoryModel, custom_error::CustomError, transaction::TransactionModel},
+  requests::{api_client::ApiClient, category, health, transaction},
+  types::{
+    request::{
+      category::{CreateCategoryRequest, UpdateCategoryRequest},
+      transaction::{CreateTransactionRequest, UpdateTransactionRequest},
+    },
+    responses::transaction::{MonthByYear, TransactionBalances, TransactionTotal},
+    year_month::YearMonth,
+  },
+};
+
+use super::Storage;
+
+/// Storage backed by the expenses API.
+pub struct HttpStorage {
+  api: ApiClient,
+}
+
+impl HttpStorage {
+  pub fn new(api: ApiClient) -> Self {
+    Self { api }
+  }
+}
+
+#[async_trait]
+impl Storage for HttpStorage {
+  async fn health(&self) -> bool {
+    matches!(health::request(&self.api).await, Ok(true))
+  }
+
+  async fn get_month_by_year(&self) -> Result<Vec<MonthByYear>, CustomError> {
+    transaction::get_month_by_year(&self.api).await
+  }
+
+  async fn get_transactions_by_month_and_type(
+    &self,
+    transaction_type: TransactionType,
+    period: &YearMonth,
+  ) -> Result<Vec<TransactionModel>, CustomError> {
+    transaction::get_transactions_by_month_and_type(&self.api, transaction_type, period).await
+  }
+
+  async fn get_transactions_balances(
+    &self,
+    period: &YearMonth,
+  ) -> Result<TransactionBalances, CustomError> {
+    transaction::get_transactions_balances(&self.api, period).await
+  }
+
+  async fn get_total_saving(&self) -> Result<TransactionTotal, CustomError> {
+    transaction::get_total_saving(&self.api).await
+  }
+
+  async fn create_transaction(
+    &self,
+    new_transaction: CreateTransactionRequest,
+  ) -> Result<TransactionModel, CustomError> {
+    transaction::create_transaction(&self.api, new_transaction).await
+  }
+
+  async fn update_transaction(
+    &self,
+    transaction_id: &str,
+    transaction: UpdateTransactionRequest,
+  ) -> Result<TransactionModel, CustomError> {
+    transaction::update_transaction(&self.api, transaction_id, transaction).await
+  }
+
+  async fn delete_transaction(&self, transaction_id: &str) -> Result<(), CustomError> {
+    transaction::delete_transaction(&self.api, transaction_id).await
+  }
+
+  async fn get_categories(&self) -> Result<Vec<CategoryModel>, CustomError> {
+    category::get_categories(&self.api).await
+  }
+
+  async fn create_category(
+    &self,
+    new_category: CreateCategoryRequest,
+  ) -> Result<CategoryModel, CustomError> {
+    category::create_category(&self.api, new_category).await
+  }
+
+  async fn update_category(
+    &self,
+    category_id: &str,
+    category: UpdateCategoryRequest,
+  ) -> Result<CategoryModel, CustomError> {
+    category::update_category(&self.api, category_id, category).await
+  }
+
+  async fn delete_category(&self, category_id: &str) -> Result<(), CustomError> {
+    category::delete_category(&self.api, category_id).await
+  }
+}
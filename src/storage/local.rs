@@ -0,0 +1,484 @@
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  env,
+  fs::File,
+  io::{BufReader, ErrorKind},
+  path::PathBuf,
+  sync::{
+    atomic::{AtomicU64, Ordering},
+    Mutex,
+  },
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  enums::{currency::CurrencyEnum, month::MonthEnum, transaction_type::TransactionType},
+  models::{category::CategoryModel, custom_error::CustomError, transaction::TransactionModel},
+  types::{
+    request::{
+      category::{CreateCategoryRequest, UpdateCategoryRequest},
+      transaction::{CreateTransactionRequest, UpdateTransactionRequest},
+    },
+    responses::transaction::{MonthByYear, TransactionBalances, TransactionTotal},
+    year_month::YearMonth,
+  },
+  utils::fs::write_private,
+};
+
+use super::Storage;
+
+const LOCAL_USER_ID: &str = "local";
+
+static ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+struct LocalData {
+  transactions: Vec<TransactionModel>,
+  categories: Vec<CategoryModel>,
+}
+
+/// Storage kept in a single JSON file, balances and listings are computed here
+/// instead of by the server.
+pub struct LocalStorage {
+  path: PathBuf,
+  data: Mutex<LocalData>,
+}
+
+/// Unique enough for ids created on this machine.
+pub fn new_id() -> String {
+  let nanos = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_nanos())
+    .unwrap_or_default();
+
+  format!(
+    "{:x}-{:x}",
+    nanos,
+    ID_COUNTER.fetch_add(1, Ordering::Relaxed)
+  )
+}
+
+fn is_in_period(transaction: &TransactionModel, period: &YearMonth) -> bool {
+  i32::from(transaction.year) == period.year && transaction.month == period.month
+}
+
+/// Sums transactions per currency, `total` is expressed in UYU using each
+/// transaction's exchange rate. Foreign amounts without a rate are left out of it.
+fn totals<'a>(transactions: impl Iterator<Item = &'a TransactionModel>) -> TransactionTotal {
+  let mut total = TransactionTotal::default();
+
+  for transaction in transactions {
+    total.add(&transaction.currency, transaction.amount);
+
+    if transaction.currency == CurrencyEnum::UYU {
+      total.total += transaction.amount;
+    } else if let Some(rate) = transaction.exchange_rate {
+      total.total += transaction.amount * rate;
+    }
+  }
+
+  total
+}
+
+impl LocalStorage {
+  pub fn default_path() -> PathBuf {
+    let dir = match env::var("XDG_DATA_HOME") {
+      Ok(dir) if !dir.is_empty() => PathBuf::from(dir),
+      _ => PathBuf::from(env::var("HOME").unwrap_or_default()).join(".local/share"),
+    };
+
+    dir.join("expenses").join("data.json")
+  }
+
+  /// Opens the store at `path`, a missing file starts an empty store.
+  pub fn open(path: PathBuf) -> Result<Self, CustomError> {
+    let data = match File::open(&path) {
+      Ok(file) => serde_json::from_reader(BufReader::new(file))?,
+      Err(err) if err.kind() == ErrorKind::NotFound => LocalData::default(),
+      Err(err) => return Err(err.into()),
+    };
+
+    Ok(Self {
+      path,
+      data: Mutex::new(data),
+    })
+  }
+
+  fn read<T>(&self, read: impl FnOnce(&LocalData) -> T) -> T {
+    let data = self.data.lock().unwrap_or_else(|err| err.into_inner());
+
+    read(&data)
+  }
+
+  /// Applies `change` to a copy of the data and only keeps it once it was written to disk.
+  fn write<T>(
+    &self,
+    change: impl FnOnce(&mut LocalData) -> Result<T, CustomError>,
+  ) -> Result<T, CustomError> {
+    let mut data = self.data.lock().unwrap_or_else(|err| err.into_inner());
+
+    let mut updated = data.clone();
+    let result = change(&mut updated)?;
+
+    write_private(&self.path, &serde_json::to_vec_pretty(&updated)?)?;
+    *data = updated;
+
+    Ok(result)
+  }
+}
+
+impl LocalData {
+  fn category(&self, category_id: &Option<String>) -> Option<CategoryModel> {
+    category_id.as_ref().and_then(|category_id| {
+      self
+        .categories
+        .iter()
+        .find(|category| &category.category_id == category_id)
+        .cloned()
+    })
+  }
+
+  fn to_transaction(
+    &self,
+    transaction_id: String,
+    request: CreateTransactionRequest,
+  ) -> TransactionModel {
+    TransactionModel {
+      transaction_id,
+      category: self.category(&request.category_id),
+      r#type: request.r#type,
+      amount: request.amount,
+      currency: request.currency,
+      note: request.note,
+      day: request.day,
+      month: request.month,
+      year: request.year,
+      exchange_rate: request.exchange_rate,
+      user_id: LOCAL_USER_ID.to_string(),
+      user: None,
+      category_id: request.category_id.unwrap_or_default(),
+    }
+  }
+}
+
+#[async_trait]
+impl Storage for LocalStorage {
+  async fn health(&self) -> bool {
+    true
+  }
+
+  async fn get_month_by_year(&self) -> Result<Vec<MonthByYear>, CustomError> {
+    let months = self.read(|data| {
+      let mut months: BTreeMap<u16, BTreeSet<MonthEnum>> = BTreeMap::new();
+
+      for transaction in &data.transactions {
+        months
+          .entry(transaction.year)
+          .or_default()
+          .insert(transaction.month.clone());
+      }
+
+      months
+    });
+
+    Ok(
+      months
+        .into_iter()
+        .map(|(year, months)| MonthByYear {
+          year: year.to_string(),
+          months: months.into_iter().map(String::from).collect(),
+        })
+        .collect(),
+    )
+  }
+
+  async fn get_transactions_by_month_and_type(
+    &self,
+    transaction_type: TransactionType,
+    period: &YearMonth,
+  ) -> Result<Vec<TransactionModel>, CustomError> {
+    Ok(self.read(|data| {
+      data
+        .transactions
+        .iter()
+        .filter(|transaction| {
+          transaction.r#type == transaction_type && is_in_period(transaction, period)
+        })
+        .cloned()
+        .collect()
+    }))
+  }
+
+  async fn get_transactions_balances(
+    &self,
+    period: &YearMonth,
+  ) -> Result<TransactionBalances, CustomError> {
+    Ok(self.read(|data| {
+      let month = || {
+        data
+          .transactions
+          .iter()
+          .filter(|transaction| is_in_period(transaction, period))
+      };
+
+      TransactionBalances {
+        incomes: totals(month().filter(|t| t.r#type == TransactionType::INCOME)),
+        expenses: totals(month().filter(|t| {
+          t.r#type == TransactionType::EXPENSE || t.r#type == TransactionType::INSTALLMENTS
+        })),
+        savings: totals(month().filter(|t| t.r#type == TransactionType::SAVING)),
+      }
+    }))
+  }
+
+  async fn get_total_saving(&self) -> Result<TransactionTotal, CustomError> {
+    Ok(self.read(|data| {
+      totals(
+        data
+          .transactions
+          .iter()
+          .filter(|t| t.r#type == TransactionType::SAVING),
+      )
+    }))
+  }
+
+  async fn create_transaction(
+    &self,
+    new_transaction: CreateTransactionRequest,
+  ) -> Result<TransactionModel, CustomError> {
+    self.write(|data| {
+      let transaction = data.to_transaction(new_id(), new_transaction);
+      data.transactions.push(transaction.clone());
+
+      Ok(transaction)
+    })
+  }
+
+  async fn update_transaction(
+    &self,
+    transaction_id: &str,
+    transaction: UpdateTransactionRequest,
+  ) -> Result<TransactionModel, CustomError> {
+    self.write(|data| {
+      let updated = data.to_transaction(transaction_id.to_string(), transaction);
+
+      let current = data
+        .transactions
+        .iter_mut()
+        .find(|transaction| transaction.transaction_id == transaction_id)
+        .ok_or_else(|| CustomError::NotFound(String::from("Transaction")))?;
+      *current = updated.clone();
+
+      Ok(updated)
+    })
+  }
+
+  async fn delete_transaction(&self, transaction_id: &str) -> Result<(), CustomError> {
+    self.write(|data| {
+      let count = data.transactions.len();
+      data
+        .transactions
+        .retain(|transaction| transaction.transaction_id != transaction_id);
+
+      if data.transactions.len() == count {
+        return Err(CustomError::NotFound(String::from("Transaction")));
+      }
+
+      Ok(())
+    })
+  }
+
+  async fn get_categories(&self) -> Result<Vec<CategoryModel>, CustomError> {
+    Ok(self.read(|data| data.categories.clone()))
+  }
+
+  async fn create_category(
+    &self,
+    new_category: CreateCategoryRequest,
+  ) -> Result<CategoryModel, CustomError> {
+    self.write(|data| {
+      let category = CategoryModel {
+        category_id: new_id(),
+        r#type: new_category.r#type,
+        name: new_category.name,
+        note: new_category.note,
+        user_id: LOCAL_USER_ID.to_string(),
+        user: None,
+      };
+      data.categories.push(category.clone());
+
+      Ok(category)
+    })
+  }
+
+  async fn update_category(
+    &self,
+    category_id: &str,
+    category: UpdateCategoryRequest,
+  ) -> Result<CategoryModel, CustomError> {
+    self.write(|data| {
+      let current = data
+        .categories
+        .iter_mut()
+        .find(|known| known.category_id == category_id)
+        .ok_or_else(|| CustomError::NotFound(String::from("Category")))?;
+
+      current.name = category.name;
+      current.r#type = category.r#type;
+      current.note = category.note;
+
+      let updated = current.clone();
+
+      for transaction in data
+        .transactions
+        .iter_mut()
+        .filter(|transaction| transaction.category_id == category_id)
+      {
+        transaction.category = Some(updated.clone());
+      }
+
+      Ok(updated)
+    })
+  }
+
+  async fn delete_category(&self, category_id: &str) -> Result<(), CustomError> {
+    self.write(|data| {
+      let count = data.categories.len();
+      data
+        .categories
+        .retain(|category| category.category_id != category_id);
+
+      if data.categories.len() == count {
+        return Err(CustomError::NotFound(String::from("Category")));
+      }
+
+      for transaction in data
+        .transactions
+        .iter_mut()
+        .filter(|transaction| transaction.category_id == category_id)
+      {
+        transaction.category_id = String::new();
+        transaction.category = None;
+      }
+
+      Ok(())
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::utils::fixtures::{month, transaction};
+
+  use rust_decimal::Decimal;
+
+  use super::*;
+
+  use CurrencyEnum::*;
+  use TransactionType::*;
+
+  /// A store over `transactions` that is never written, reads don't touch the file.
+  fn storage(transactions: Vec<TransactionModel>) -> LocalStorage {
+    LocalStorage {
+      path: PathBuf::new(),
+      data: Mutex::new(LocalData {
+        transactions,
+        categories: Vec::new(),
+      }),
+    }
+  }
+
+  #[tokio::test]
+  async fn balances_sum_the_month_per_currency_and_in_uyu() {
+    let storage = storage(vec![
+      transaction(INCOME, 1000).build(),
+      transaction(INCOME, 10)
+        .currency(USD)
+        .exchange_rate(40)
+        .build(),
+      transaction(EXPENSE, 200).build(),
+      transaction(INSTALLMENTS, 5)
+        .currency(USD)
+        .exchange_rate(40)
+        .build(),
+      transaction(EXPENSE, 7).currency(EUR).build(),
+      transaction(SAVING, 300).build(),
+      transaction(EXPENSE, 999).period("2023-04").build(),
+      transaction(EXPENSE, 999).period("2022-03").build(),
+    ]);
+
+    let balances = storage
+      .get_transactions_balances(&month("2023-03"))
+      .await
+      .unwrap();
+
+    assert_eq!(balances.incomes.total, Decimal::from(1400));
+    assert_eq!(balances.incomes.amount(&UYU), Decimal::from(1000));
+    assert_eq!(balances.incomes.amount(&USD), Decimal::from(10));
+
+    // The EUR expense has no rate, it is only counted in its own currency.
+    assert_eq!(balances.expenses.total, Decimal::from(400));
+    assert_eq!(balances.expenses.amount(&UYU), Decimal::from(200));
+    assert_eq!(balances.expenses.amount(&USD), Decimal::from(5));
+    assert_eq!(balances.expenses.amount(&EUR), Decimal::from(7));
+
+    assert_eq!(balances.savings.total, Decimal::from(300));
+    assert_eq!(balances.savings.currencies.len(), 1);
+  }
+
+  #[tokio::test]
+  async fn total_saving_counts_every_month_and_currency() {
+    let storage = storage(vec![
+      transaction(SAVING, 300).build(),
+      transaction(SAVING, 100).period("2022-07").build(),
+      transaction(SAVING, 20)
+        .currency(USD)
+        .exchange_rate(40)
+        .period("2023-04")
+        .build(),
+      transaction(SAVING, 50)
+        .currency(EUR)
+        .period("2023-04")
+        .build(),
+      transaction(INCOME, 1000).build(),
+    ]);
+
+    let total = storage.get_total_saving().await.unwrap();
+
+    assert_eq!(total.total, Decimal::from(1200));
+    assert_eq!(total.amount(&UYU), Decimal::from(400));
+    assert_eq!(total.amount(&USD), Decimal::from(20));
+    assert_eq!(total.amount(&EUR), Decimal::from(50));
+  }
+
+  #[tokio::test]
+  async fn months_by_year_are_sorted_without_duplicates() {
+    let storage = storage(vec![
+      transaction(EXPENSE, 1).build(),
+      transaction(INCOME, 1).period("2023-01").build(),
+      transaction(SAVING, 1).build(),
+      transaction(EXPENSE, 1).period("2022-12").build(),
+    ]);
+
+    let months = storage.get_month_by_year().await.unwrap();
+    let months: Vec<(&str, Vec<&str>)> = months
+      .iter()
+      .map(|item| {
+        let months = item.months.iter().map(String::as_str).collect();
+        (item.year.as_str(), months)
+      })
+      .collect();
+
+    assert_eq!(
+      months,
+      vec![
+        ("2022", vec!["DECEMBER"]),
+        ("2023", vec!["JANUARY", "MARCH"]),
+      ]
+    );
+  }
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+  enums::transaction_type::TransactionType,
+  models::{category::CategoryModel, custom_error::CustomError, transaction::TransactionModel},
+  types::{
+    request::{
+      category::{CreateCategoryRequest, UpdateCategoryRequest},
+      transaction::{CreateTransactionRequest, UpdateTransactionRequest},
+    },
+    responses::transaction::{MonthByYear, TransactionBalances, TransactionTotal},
+    year_month::YearMonth,
+  },
+};
+
+pub mod http;
+pub mod local;
+
+pub type SharedStorage = Arc<dyn Storage>;
+
+/// Where transactions and categories are kept, either the API server or a local file.
+#[async_trait]
+pub trait Storage: Send + Sync {
+  /// Whether the backend can be reached right now.
+  async fn health(&self) -> bool;
+
+  async fn get_month_by_year(&self) -> Result<Vec<MonthByYear>, CustomError>;
+
+  async fn get_transactions_by_month_and_type(
+    &self,
+    transaction_type: TransactionType,
+    period: &YearMonth,
+  ) -> Result<Vec<TransactionModel>, CustomError>;
+
+  async fn get_transactions_balances(
+    &self,
+    period: &YearMonth,
+  ) -> Result<TransactionBalances, CustomError>;
+
+  /// Savings accumulated over every month, per currency.
+  async fn get_total_saving(&self) -> Result<TransactionTotal, CustomError>;
+
+  async fn create_transaction(
+    &self,
+    new_transaction: CreateTransactionRequest,
+  ) -> Result<TransactionModel, CustomError>;
+
+  async fn update_transaction(
+    &self,
+    transaction_id: &str,
+    transaction: UpdateTransactionRequest,
+  ) -> Result<TransactionModel, CustomError>;
+
+  async fn delete_transaction(&self, transaction_id: &str) -> Result<(), CustomError>;
+
+  async fn get_categories(&self) -> Result<Vec<CategoryModel>, CustomError>;
+
+  async fn create_category(
+    &self,
+    new_category: CreateCategoryRequest,
+  ) -> Result<CategoryModel, CustomError>;
+
+  async fn update_category(
+    &self,
+    category_id: &str,
+    category: UpdateCategoryRequest,
+  ) -> Result<CategoryModel, CustomError>;
+
+  async fn delete_category(&self, category_id: &str) -> Result<(), CustomError>;
+}
@@ -1,11 +1,33 @@
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::enums::{backend::Backend, currency::CurrencyEnum, language::Language};
+
+pub mod money;
 pub mod request;
 pub mod responses;
+pub mod year_month;
 
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Config {
   pub session_id: Option<String>,
+  /// Account the session belongs to, keys the offline cache.
+  pub user_id: Option<String>,
+  #[serde(default)]
   pub url: String,
+  #[serde(default)]
+  pub language: Language,
+  #[serde(default)]
+  pub backend: Backend,
+  /// File used by the local backend, defaults to `~/.local/share/expenses/data.json`.
+  pub data_path: Option<String>,
+  /// Currency the summaries and tables are converted to.
+  #[serde(default)]
+  pub display_currency: CurrencyEnum,
+  /// Fallback rates in UYU per unit for transactions without their own rate.
+  #[serde(default)]
+  pub exchange_rates: HashMap<CurrencyEnum, Decimal>,
 }
@@ -0,0 +1,147 @@
+use std::fmt;
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::enums::{currency::CurrencyEnum, language::Language};
+
+/// An exact amount in a given currency.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Money {
+  pub amount: Decimal,
+  pub currency: CurrencyEnum,
+}
+
+/// Groups the integer digits with `thousands` and joins the fraction with `decimal`.
+fn format_number(amount: Decimal, decimals: u32, thousands: char, decimal: char) -> String {
+  let digits = format!("{:.*}", decimals as usize, amount.abs());
+  let (integer, fraction) = match digits.split_once('.') {
+    Some((integer, fraction)) => (integer, Some(fraction)),
+    None => (digits.as_str(), None),
+  };
+
+  let mut grouped = String::new();
+  for (index, digit) in integer.chars().enumerate() {
+    if index > 0 && (integer.len() - index) % 3 == 0 {
+      grouped.push(thousands);
+    }
+    grouped.push(digit);
+  }
+
+  if let Some(fraction) = fraction {
+    grouped.push(decimal);
+    grouped.push_str(fraction);
+  }
+
+  grouped
+}
+
+impl Money {
+  pub fn new(amount: Decimal, currency: CurrencyEnum) -> Self {
+    Self { amount, currency }
+  }
+
+  /// Rounds half away from zero to the currency's minor unit.
+  pub fn rounded(&self) -> Decimal {
+    self.amount.round_dp_with_strategy(
+      self.currency.minor_units(),
+      RoundingStrategy::MidpointAwayFromZero,
+    )
+  }
+
+  /// Formats as `$1,234.50 USD`, or `$1.234,50 USD` for es-UY.
+  pub fn format(&self, language: &Language) -> String {
+    let (thousands, decimal) = match language {
+      Language::EN => (',', '.'),
+      Language::ES => ('.', ','),
+    };
+
+    let amount = self.rounded();
+
+    format!(
+      "{}${} {}",
+      if amount.is_sign_negative() && !amount.is_zero() {
+        "-"
+      } else {
+        ""
+      },
+      format_number(amount, self.currency.minor_units(), thousands, decimal),
+      self.currency
+    )
+  }
+}
+
+impl fmt::Display for Money {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.format(&Language::EN))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn money(amount: &str, currency: CurrencyEnum) -> Money {
+    Money::new(amount.parse().unwrap(), currency)
+  }
+
+  #[test]
+  fn rounds_half_away_from_zero_to_the_minor_unit() {
+    assert_eq!(
+      money("1.005", CurrencyEnum::USD).format(&Language::EN),
+      "$1.01 USD"
+    );
+    assert_eq!(
+      money("-1.005", CurrencyEnum::USD).format(&Language::EN),
+      "-$1.01 USD"
+    );
+    assert_eq!(
+      money("1.004", CurrencyEnum::USD).format(&Language::EN),
+      "$1.00 USD"
+    );
+    assert_eq!(
+      money("1234.5", CurrencyEnum::from_code("JPY").unwrap()).format(&Language::EN),
+      "$1,235 JPY"
+    );
+    assert_eq!(
+      money("1.2345", CurrencyEnum::from_code("KWD").unwrap()).format(&Language::EN),
+      "$1.235 KWD"
+    );
+  }
+
+  #[test]
+  fn amounts_rounding_to_zero_have_no_sign() {
+    assert_eq!(
+      money("-0.004", CurrencyEnum::USD).format(&Language::EN),
+      "$0.00 USD"
+    );
+  }
+
+  #[test]
+  fn groups_thousands_per_language() {
+    let amount = money("1234567.5", CurrencyEnum::UYU);
+
+    assert_eq!(amount.format(&Language::EN), "$1,234,567.50 UYU");
+    assert_eq!(amount.format(&Language::ES), "$1.234.567,50 UYU");
+    assert_eq!(
+      money("123", CurrencyEnum::UYU).format(&Language::ES),
+      "$123,00 UYU"
+    );
+    assert_eq!(
+      money("-1000", CurrencyEnum::EUR).format(&Language::EN),
+      "-$1,000.00 EUR"
+    );
+  }
+
+  #[test]
+  fn amounts_round_trip_through_json_exactly() {
+    let amount = money("0.1", CurrencyEnum::UYU).amount + money("0.2", CurrencyEnum::UYU).amount;
+    let json = serde_json::to_string(&amount).unwrap();
+
+    assert_eq!(json, "0.3");
+    assert_eq!(serde_json::from_str::<Decimal>(&json).unwrap(), amount);
+    assert_eq!(
+      serde_json::from_str::<Decimal>("1234567.89").unwrap(),
+      money("1234567.89", CurrencyEnum::UYU).amount
+    );
+  }
+}
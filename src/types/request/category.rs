@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+
+use crate::enums::transaction_type::TransactionType;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCategoryRequest {
+  pub name: String,
+  pub r#type: TransactionType,
+  pub note: Option<String>,
+}
+
+pub type UpdateCategoryRequest = CreateCategoryRequest;
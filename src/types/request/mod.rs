@@ -1 +1,3 @@
+pub mod category;
+pub mod transaction;
 pub mod user;
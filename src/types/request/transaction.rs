@@ -0,0 +1,20 @@
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+
+use crate::enums::{currency::CurrencyEnum, month::MonthEnum, transaction_type::TransactionType};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTransactionRequest {
+  pub r#type: TransactionType,
+  pub amount: Decimal,
+  pub currency: CurrencyEnum,
+  pub exchange_rate: Option<Decimal>,
+  pub day: Option<u8>,
+  pub month: MonthEnum,
+  pub year: u16,
+  pub note: Option<String>,
+  pub category_id: Option<String>,
+}
+
+pub type UpdateTransactionRequest = CreateTransactionRequest;
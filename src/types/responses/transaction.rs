@@ -1,14 +1,78 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
-#[derive(Deserialize, Serialize, Debug)]
+use crate::enums::currency::CurrencyEnum;
+
+/// Totals per currency, `total` is expressed in UYU. The server sends them as
+/// `{ "total": 0, "uyu": 0, "usd": 0, ... }` with one key per currency present.
+/// Fields that aren't amounts of `total` or an ISO-4217 currency are skipped.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(try_from = "Map<String, Value>", into = "BTreeMap<String, Decimal>")]
 pub struct TransactionTotal {
-  pub total: f64,
-  pub uyu: f64,
-  pub usd: f64,
-  pub eur: f64,
+  pub total: Decimal,
+  pub currencies: BTreeMap<CurrencyEnum, Decimal>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+impl TransactionTotal {
+  pub fn amount(&self, currency: &CurrencyEnum) -> Decimal {
+    self.currencies.get(currency).copied().unwrap_or_default()
+  }
+
+  pub fn add(&mut self, currency: &CurrencyEnum, amount: Decimal) {
+    *self.currencies.entry(currency.clone()).or_default() += amount;
+  }
+}
+
+fn to_decimal(value: &Value) -> Option<Decimal> {
+  let number = value.as_number()?.to_string();
+
+  Decimal::from_str(&number)
+    .or_else(|_| Decimal::from_scientific(&number))
+    .ok()
+}
+
+impl TryFrom<Map<String, Value>> for TransactionTotal {
+  type Error = String;
+
+  fn try_from(values: Map<String, Value>) -> Result<Self, Self::Error> {
+    let mut total = TransactionTotal::default();
+
+    for (key, value) in &values {
+      if !value.is_number() {
+        continue;
+      }
+
+      let amount =
+        to_decimal(value).ok_or_else(|| format!("invalid amount {} for {}", value, key))?;
+
+      if key == "total" {
+        total.total = amount;
+      } else if let Some(currency) = CurrencyEnum::from_code(key) {
+        total.add(&currency, amount);
+      }
+    }
+
+    Ok(total)
+  }
+}
+
+impl From<TransactionTotal> for BTreeMap<String, Decimal> {
+  fn from(total: TransactionTotal) -> Self {
+    let mut values: BTreeMap<String, Decimal> = total
+      .currencies
+      .into_iter()
+      .map(|(currency, amount)| (currency.code().to_lowercase(), amount))
+      .collect();
+    values.insert(String::from("total"), total.total);
+
+    values
+  }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
 pub struct TransactionBalances {
   pub incomes: TransactionTotal,
   pub expenses: TransactionTotal,
@@ -18,11 +82,57 @@ pub struct TransactionBalances {
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct GetTotalSaving {
-  pub total_savings: f64,
+  pub total_savings: Decimal,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonthByYear {
   pub year: String,
   pub months: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn totals_read_every_currency_exactly() {
+    let total: TransactionTotal =
+      serde_json::from_str(r#"{ "total": 450.1, "uyu": 50.1, "usd": 10, "gbp": 0.3 }"#).unwrap();
+
+    assert_eq!(total.total, Decimal::from_str("450.1").unwrap());
+    assert_eq!(
+      total.amount(&CurrencyEnum::UYU),
+      Decimal::from_str("50.1").unwrap()
+    );
+    assert_eq!(total.amount(&CurrencyEnum::USD), Decimal::from(10));
+    assert_eq!(
+      total.amount(&CurrencyEnum::Other(String::from("GBP"))),
+      Decimal::from_str("0.3").unwrap()
+    );
+  }
+
+  #[test]
+  fn totals_skip_fields_that_are_not_amounts() {
+    let total: TransactionTotal =
+      serde_json::from_str(r#"{ "total": 5, "uyu": 5, "usd": null, "updatedAt": "2023-03-01" }"#)
+        .unwrap();
+
+    assert_eq!(total.total, Decimal::from(5));
+    assert_eq!(total.currencies.len(), 1);
+  }
+
+  #[test]
+  fn totals_skip_amounts_that_are_not_a_currency() {
+    let total: TransactionTotal = serde_json::from_str(
+      r#"{ "total": 5, "uyu": 5, "sum": 7, "day": 12, "count": 3, "u$s": 1 }"#,
+    )
+    .unwrap();
+
+    assert_eq!(total.total, Decimal::from(5));
+    assert_eq!(
+      total.currencies.into_iter().collect::<Vec<_>>(),
+      vec![(CurrencyEnum::UYU, Decimal::from(5))]
+    );
+  }
+}
@@ -0,0 +1,212 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{Datelike, Local, NaiveDate};
+
+use crate::enums::month::MonthEnum;
+
+/// A calendar month of a specific year, ordered chronologically.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonth {
+  pub year: i32,
+  pub month: MonthEnum,
+}
+
+impl YearMonth {
+  pub fn new(year: i32, month: MonthEnum) -> Self {
+    Self { year, month }
+  }
+
+  pub fn now() -> Self {
+    Self::from_date(&Local::now())
+  }
+
+  pub fn from_date(date: &impl Datelike) -> Self {
+    Self {
+      year: date.year(),
+      month: MonthEnum::from_u32(date.month()).unwrap_or(MonthEnum::JANUARY),
+    }
+  }
+
+  /// The twelve months of a year, January first.
+  pub fn months_of(year: i32) -> Vec<Self> {
+    (1..=12)
+      .filter_map(MonthEnum::from_u32)
+      .map(|month| Self::new(year, month))
+      .collect()
+  }
+
+  /// Months elapsed since January of year 0, used for month arithmetic.
+  fn index(&self) -> i32 {
+    self.year * 12 + self.month.to_u32() as i32 - 1
+  }
+
+  fn from_index(index: i32) -> Self {
+    let month = MonthEnum::from_u32(index.rem_euclid(12) as u32 + 1).unwrap_or(MonthEnum::JANUARY);
+
+    Self::new(index.div_euclid(12), month)
+  }
+
+  pub fn add_months(&self, months: i32) -> Self {
+    Self::from_index(self.index() + months)
+  }
+
+  /// Months from `self` to `other`, negative when `other` is earlier.
+  pub fn months_until(&self, other: &YearMonth) -> i32 {
+    other.index() - self.index()
+  }
+
+  /// The `day` of this month, `None` when the month is shorter, e.g. February 31st.
+  pub fn date(&self, day: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(self.year, self.month.to_u32(), day)
+  }
+
+  /// Number of days in the month.
+  pub fn days(&self) -> u32 {
+    (28..=31)
+      .rev()
+      .find(|day| self.date(*day).is_some())
+      .unwrap_or(28)
+  }
+
+  /// Checks a transaction day against the length of the month.
+  pub fn validate_day(&self, day: u8) -> Result<(), String> {
+    match self.date(day as u32) {
+      Some(_) => Ok(()),
+      None => Err(format!(
+        "Day must be between 1 and {} for {}",
+        self.days(),
+        self
+      )),
+    }
+  }
+
+  pub fn next(&self) -> Self {
+    match self.month {
+      MonthEnum::DECEMBER => Self::new(self.year + 1, MonthEnum::JANUARY),
+      _ => Self::new(self.year, self.month.next()),
+    }
+  }
+
+  pub fn previous(&self) -> Self {
+    match self.month {
+      MonthEnum::JANUARY => Self::new(self.year - 1, MonthEnum::DECEMBER),
+      _ => Self::new(self.year, self.month.previous()),
+    }
+  }
+}
+
+impl Default for YearMonth {
+  fn default() -> Self {
+    Self::now()
+  }
+}
+
+impl fmt::Display for YearMonth {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:04}-{:02}", self.year, self.month.to_u32())
+  }
+}
+
+impl FromStr for YearMonth {
+  type Err = String;
+
+  /// Parses `YYYY-MM`, e.g. `2023-03`.
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let invalid = || format!("Invalid month {}, expected YYYY-MM", value);
+
+    let (year, month) = value.trim().split_once('-').ok_or_else(invalid)?;
+    let year = year.parse::<i32>().map_err(|_| invalid())?;
+    let month = month
+      .parse::<u32>()
+      .ok()
+      .and_then(MonthEnum::from_u32)
+      .ok_or_else(invalid)?;
+
+    Ok(Self::new(year, month))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::utils::fixtures::month;
+
+  use super::*;
+
+  use MonthEnum::*;
+
+  #[test]
+  fn parses_and_displays_year_and_month() {
+    assert_eq!("2023-03".parse(), Ok(YearMonth::new(2023, MARCH)));
+    assert_eq!(" 2024-12 ".parse(), Ok(YearMonth::new(2024, DECEMBER)));
+    assert_eq!(YearMonth::new(2023, MARCH).to_string(), "2023-03");
+    assert_eq!(YearMonth::new(987, OCTOBER).to_string(), "0987-10");
+  }
+
+  #[test]
+  fn parse_rejects_invalid_months() {
+    for value in ["2024-13", "2024-00", "2024-1x", "2024", "x-03", ""] {
+      assert_eq!(
+        value.parse::<YearMonth>(),
+        Err(format!("Invalid month {}, expected YYYY-MM", value))
+      );
+    }
+  }
+
+  #[test]
+  fn next_and_previous_roll_over_the_year() {
+    assert_eq!(
+      YearMonth::new(2023, DECEMBER).next(),
+      YearMonth::new(2024, JANUARY)
+    );
+    assert_eq!(
+      YearMonth::new(2024, JANUARY).previous(),
+      YearMonth::new(2023, DECEMBER)
+    );
+    assert_eq!(
+      YearMonth::new(2023, MARCH).next(),
+      YearMonth::new(2023, APRIL)
+    );
+    assert_eq!(
+      YearMonth::new(2023, MARCH).previous(),
+      YearMonth::new(2023, FEBRUARY)
+    );
+  }
+
+  #[test]
+  fn validate_day_uses_the_length_of_the_month() {
+    assert_eq!(YearMonth::new(2024, FEBRUARY).validate_day(29), Ok(()));
+    assert_eq!(
+      YearMonth::new(2023, FEBRUARY).validate_day(29),
+      Err(String::from("Day must be between 1 and 28 for 2023-02"))
+    );
+    assert_eq!(YearMonth::new(2023, DECEMBER).validate_day(31), Ok(()));
+    assert!(YearMonth::new(2023, APRIL).validate_day(31).is_err());
+    assert!(YearMonth::new(2023, APRIL).validate_day(0).is_err());
+  }
+
+  #[test]
+  fn months_of_lists_the_year_from_january_to_december() {
+    let months = YearMonth::months_of(2023);
+    let numbers: Vec<u32> = months.iter().map(|month| month.month.to_u32()).collect();
+
+    assert_eq!(numbers, (1..=12).collect::<Vec<u32>>());
+    assert!(months.iter().all(|month| month.year == 2023));
+  }
+
+  #[test]
+  fn add_months_crosses_year_boundaries() {
+    assert_eq!(month("2023-11").add_months(3), month("2024-02"));
+    assert_eq!(month("2023-01").add_months(-1), month("2022-12"));
+    assert_eq!(month("2023-03").add_months(-15), month("2021-12"));
+    assert_eq!(month("2023-12").add_months(12), month("2024-12"));
+    assert_eq!(month("2023-05").add_months(0), month("2023-05"));
+  }
+
+  #[test]
+  fn months_until_is_negative_for_earlier_months() {
+    assert_eq!(month("2023-11").months_until(&month("2024-02")), 3);
+    assert_eq!(month("2024-02").months_until(&month("2023-11")), -3);
+    assert_eq!(month("2022-01").months_until(&month("2023-12")), 23);
+    assert_eq!(month("2023-06").months_until(&month("2023-06")), 0);
+  }
+}
@@ -0,0 +1,71 @@
+use tui::{
+  backend::Backend,
+  layout::{Alignment, Constraint, Direction, Layout},
+  style::Style,
+  text::{Span, Spans},
+  widgets::{Block, Borders, Clear, Paragraph, Wrap},
+  Frame,
+};
+
+use crate::{enums::currency::CurrencyEnum, states::budget_form::BudgetFormState};
+
+use super::{
+  colors::*,
+  inputs::{create_input, set_input_cursor},
+  popup::centered_rect,
+};
+
+pub fn budget_form_ui<B: Backend>(
+  frame: &mut Frame<B>,
+  form: &BudgetFormState,
+  currency: &CurrencyEnum,
+) {
+  let area = centered_rect(40, 9, frame.size());
+
+  let block = Block::default()
+    .title(format!("Budget for {}", form.name))
+    .borders(Borders::ALL)
+    .style(Style::default().bg(BACKGROUND).fg(ORANGE));
+
+  let inner = block.inner(area);
+
+  frame.render_widget(Clear, area);
+  frame.render_widget(block, area);
+
+  let layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(
+      [
+        Constraint::Length(3),
+        Constraint::Length(2),
+        Constraint::Length(1),
+      ]
+      .as_ref(),
+    )
+    .split(inner);
+
+  let title = format!("Monthly limit ({})", currency);
+  frame.render_widget(
+    create_input(&title, &form.limit, true, false, layout[0].width),
+    layout[0],
+  );
+
+  if let Some(error) = &form.error {
+    let error_paragraph = Paragraph::new(Spans::from(Span::styled(
+      error.clone(),
+      Style::default().fg(RED),
+    )))
+    .wrap(Wrap { trim: true });
+
+    frame.render_widget(error_paragraph, layout[1]);
+  }
+
+  let help = Paragraph::new(Spans::from(Span::styled(
+    "Enter: save | empty: remove | Esc: cancel",
+    Style::default().fg(CYAN),
+  )))
+  .alignment(Alignment::Center);
+  frame.render_widget(help, layout[2]);
+
+  set_input_cursor(frame, &form.limit, layout[0]);
+}
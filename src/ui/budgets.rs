@@ -0,0 +1,120 @@
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use tui::{
+  backend::Backend,
+  layout::{Constraint, Direction, Layout, Rect},
+  style::{Color, Modifier, Style},
+  text::{Span, Spans},
+  widgets::{Gauge, Paragraph},
+  Frame,
+};
+
+use crate::{
+  enums::selected_block::SelectedBlock,
+  states::{budgets::BudgetRow, App},
+  types::money::Money,
+};
+
+use super::{blocks::create_block, colors::*};
+
+/// Lines taken by each budget: a label and its gauge.
+const ROW_HEIGHT: u16 = 2;
+
+/// Share of the limit from which a budget is shown as close to being exceeded.
+const NEAR_LIMIT: f64 = 0.8;
+
+fn budget_color(row: &BudgetRow, ratio: f64) -> Color {
+  if row.is_over() {
+    RED
+  } else if ratio >= NEAR_LIMIT {
+    YELLOW
+  } else {
+    GREEN
+  }
+}
+
+fn budget_label(row: &BudgetRow, app: &App) -> String {
+  let currency = &app.budgets.store.data.currency;
+  let spent = Money::new(row.spent, currency.clone()).format(&app.language);
+
+  let money = |amount: Decimal| Money::new(amount, currency.clone()).format(&app.language);
+
+  let label = match (row.limit, row.remaining()) {
+    (Some(limit), Some(remaining)) if row.is_over() => {
+      format!("{} / {}, {} over", spent, money(limit), money(-remaining))
+    }
+    (Some(limit), Some(remaining)) => {
+      format!("{} / {}, {} left", spent, money(limit), money(remaining))
+    }
+    _ => format!("{} spent, no budget", spent),
+  };
+
+  match row.missing {
+    0 => label,
+    missing => format!("{} ({} without rate)", label, missing),
+  }
+}
+
+pub fn budgets_ui<B: Backend>(frame: &mut Frame<B>, app: &App, area: Rect) {
+  let block = create_block(
+    format!("Budgets {}", app.tree.current),
+    &app.selected_block,
+    SelectedBlock::Budgets,
+    CYAN,
+  );
+  let inner = block.inner(area);
+  frame.render_widget(block, area);
+
+  let rows = &app.budgets.rows;
+  let visible = ((inner.height / ROW_HEIGHT) as usize).max(1);
+  let offset = (app.budgets.selected + 1).saturating_sub(visible);
+
+  let layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(vec![Constraint::Length(ROW_HEIGHT); visible])
+    .split(inner);
+
+  for ((index, row), area) in rows.iter().enumerate().skip(offset).zip(layout) {
+    let selected = app.selected_block == SelectedBlock::Budgets && index == app.budgets.selected;
+
+    let title_style = if selected {
+      Style::default().fg(YELLOW).add_modifier(Modifier::BOLD)
+    } else {
+      Style::default().fg(FOREGROUND)
+    };
+
+    let ratio = row
+      .ratio()
+      .and_then(|ratio| ratio.to_f64())
+      .unwrap_or_default();
+    let color = match row.limit {
+      Some(_) => budget_color(row, ratio),
+      None => FOREGROUND,
+    };
+
+    let parts = Layout::default()
+      .direction(Direction::Vertical)
+      .constraints([Constraint::Length(1), Constraint::Length(1)].as_ref())
+      .split(area);
+
+    frame.render_widget(
+      Paragraph::new(Spans::from(vec![
+        Span::styled(format!("{}  ", row.name), title_style),
+        Span::styled(budget_label(row, app), Style::default().fg(color)),
+      ])),
+      parts[0],
+    );
+
+    let percent = row
+      .ratio()
+      .map(|ratio| format!("{}%", (ratio * Decimal::ONE_HUNDRED).round()))
+      .unwrap_or_default();
+
+    frame.render_widget(
+      Gauge::default()
+        .gauge_style(Style::default().fg(color).bg(GREY))
+        .ratio(ratio.clamp(0_f64, 1_f64))
+        .label(Span::styled(percent, Style::default().fg(FOREGROUND))),
+      parts[1],
+    );
+  }
+}
@@ -0,0 +1,104 @@
+use tui::{
+  backend::Backend,
+  layout::{Layout, Direction, Constraint, Alignment},
+  style::Style,
+  text::{Spans, Span},
+  widgets::{Block, Borders, Clear, Paragraph, Wrap},
+  Frame,
+};
+
+use crate::{enums::category_form_field::CategoryFormField, states::category_form::CategoryFormState};
+
+use super::{
+  colors::*,
+  inputs::{create_input, create_select, set_input_cursor},
+  popup::centered_rect,
+};
+
+pub fn category_form_ui<B: Backend>(frame: &mut Frame<B>, form: &CategoryFormState) {
+  let area = centered_rect(40, 15, frame.size());
+
+  let block = Block::default()
+    .title(match form.category_id {
+      Some(_) => "Edit category",
+      None => "New category",
+    })
+    .borders(Borders::ALL)
+    .style(Style::default().bg(BACKGROUND).fg(ORANGE));
+
+  let inner = block.inner(area);
+
+  frame.render_widget(Clear, area);
+  frame.render_widget(block, area);
+
+  let layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(
+      [
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(2),
+        Constraint::Length(1),
+      ]
+      .as_ref(),
+    )
+    .split(inner);
+
+  frame.render_widget(
+    create_input(
+      "Name",
+      &form.name,
+      form.selected_field == CategoryFormField::Name,
+      false,
+      layout[0].width,
+    ),
+    layout[0],
+  );
+
+  frame.render_widget(
+    create_select(
+      "Type",
+      format!("{:?}", form.transaction_type),
+      form.selected_field == CategoryFormField::Type,
+    ),
+    layout[1],
+  );
+
+  frame.render_widget(
+    create_input(
+      "Note",
+      &form.note,
+      form.selected_field == CategoryFormField::Note,
+      false,
+      layout[2].width,
+    ),
+    layout[2],
+  );
+
+  if let Some(error) = &form.error {
+    let error_paragraph = Paragraph::new(Spans::from(Span::styled(
+      error.clone(),
+      Style::default().fg(RED),
+    )))
+    .wrap(Wrap { trim: true });
+
+    frame.render_widget(error_paragraph, layout[3]);
+  }
+
+  let help = Paragraph::new(Spans::from(Span::styled(
+    "Tab: next field | Enter: save | Esc: cancel",
+    Style::default().fg(CYAN),
+  )))
+  .alignment(Alignment::Center);
+  frame.render_widget(help, layout[4]);
+
+  if let Some(input) = form.selected_input() {
+    let input_area = match form.selected_field {
+      CategoryFormField::Name => layout[0],
+      _ => layout[2],
+    };
+
+    set_input_cursor(frame, input, input_area);
+  }
+}
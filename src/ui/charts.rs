@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use tui::{
+  backend::Backend,
+  layout::{Constraint, Direction, Layout, Rect},
+  style::{Color, Style},
+  symbols,
+  text::Span,
+  widgets::{Axis, BarChart, Chart, Dataset, GraphType},
+  Frame,
+};
+
+use crate::{enums::selected_block::SelectedBlock, models::transaction::TransactionModel, states::App};
+
+use super::{blocks::create_block, colors::*};
+
+/// Widest bar of the category breakdown, so a few categories don't fill the screen.
+const MAX_BAR_WIDTH: u16 = 12;
+
+fn to_f64(amount: Decimal) -> f64 {
+  amount.to_f64().unwrap_or_default()
+}
+
+fn dataset<'a>(name: &'a str, data: &'a [(f64, f64)], color: Color) -> Dataset<'a> {
+  Dataset::default()
+    .name(name)
+    .marker(symbols::Marker::Braille)
+    .graph_type(GraphType::Line)
+    .style(Style::default().fg(color))
+    .data(data)
+}
+
+/// Incomes, expenses and savings of every month. Totals come in UYU and are
+/// converted to the display currency when there is a rate for it.
+fn trends_chart<B: Backend>(frame: &mut Frame<B>, app: &App, area: Rect) {
+  let currency = app.converter.convert_uyu(Decimal::ZERO).currency;
+  let convert = |amount: Decimal| to_f64(app.converter.convert_uyu(amount).amount);
+
+  let points = |total: fn(&(_, _)) -> Decimal| -> Vec<(f64, f64)> {
+    app
+      .charts
+      .trends
+      .iter()
+      .enumerate()
+      .map(|(index, month)| (index as f64, convert(total(month))))
+      .collect()
+  };
+
+  let incomes = points(|(_, balances)| balances.incomes.total);
+  let expenses = points(|(_, balances)| balances.expenses.total);
+  let savings = points(|(_, balances)| balances.savings.total);
+
+  let values = incomes
+    .iter()
+    .chain(&expenses)
+    .chain(&savings)
+    .map(|(_, y)| *y);
+  let min = values.clone().fold(0_f64, f64::min);
+  let max = values.fold(0_f64, f64::max).max(1_f64) * 1.1;
+
+  let last = app.charts.trends.len().saturating_sub(1);
+  let x_labels: Vec<Span> = match app.charts.trends.as_slice() {
+    [] => Vec::new(),
+    [(period, _)] => vec![Span::raw(period.to_string())],
+    trends => vec![
+      Span::raw(trends[0].0.to_string()),
+      Span::raw(trends[last / 2].0.to_string()),
+      Span::raw(trends[last].0.to_string()),
+    ],
+  };
+
+  let title = if app.charts.loading {
+    format!("Monthly trends ({}) loading...", currency)
+  } else {
+    format!("Monthly trends ({})", currency)
+  };
+
+  let chart = Chart::new(vec![
+    dataset("Incomes", &incomes, GREEN),
+    dataset("Expenses", &expenses, RED),
+    dataset("Savings", &savings, CYAN),
+  ])
+  .block(create_block(
+    title,
+    &app.selected_block,
+    SelectedBlock::Charts,
+    YELLOW,
+  ))
+  .x_axis(
+    Axis::default()
+      .style(Style::default().fg(FOREGROUND))
+      .bounds([0_f64, last.max(1) as f64])
+      .labels(x_labels),
+  )
+  .y_axis(
+    Axis::default()
+      .style(Style::default().fg(FOREGROUND))
+      .bounds([min, max])
+      .labels(vec![
+        Span::raw(format!("{:.0}", min)),
+        Span::raw(format!("{:.0}", (min + max) / 2_f64)),
+        Span::raw(format!("{:.0}", max)),
+      ]),
+  )
+  .hidden_legend_constraints((Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)));
+
+  frame.render_widget(chart, area);
+}
+
+/// Expenses of the selected month grouped by category, in the display currency.
+fn categories_chart<B: Backend>(frame: &mut Frame<B>, app: &App, area: Rect) {
+  let mut groups: BTreeMap<&str, Vec<&TransactionModel>> = BTreeMap::new();
+
+  for transaction in &app.table_state.row_expenses {
+    let name = match &transaction.category {
+      Some(category) => category.name.as_str(),
+      None => "Uncategorized",
+    };
+
+    groups.entry(name).or_default().push(transaction);
+  }
+
+  let mut missing = 0;
+  let mut totals: Vec<(&str, u64)> = groups
+    .into_iter()
+    .map(|(name, transactions)| {
+      let total = app.converter.total(transactions);
+      missing += total.missing;
+
+      (name, total.amount.round().to_u64().unwrap_or_default())
+    })
+    .collect();
+
+  totals.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+  let title = match missing {
+    0 => format!(
+      "Expenses by category {} ({})",
+      app.tree.current, app.converter.display
+    ),
+    missing => format!(
+      "Expenses by category {} ({}, {} without rate)",
+      app.tree.current, app.converter.display, missing
+    ),
+  };
+
+  let count = totals.len().max(1) as u16;
+  let bar_width = (area.width.saturating_sub(2) / count)
+    .saturating_sub(1)
+    .clamp(1, MAX_BAR_WIDTH);
+
+  let chart = BarChart::default()
+    .block(create_block(
+      title,
+      &app.selected_block,
+      SelectedBlock::Charts,
+      YELLOW,
+    ))
+    .data(&totals)
+    .bar_width(bar_width)
+    .bar_gap(1)
+    .bar_style(Style::default().fg(RED))
+    .value_style(Style::default().fg(BACKGROUND).bg(RED))
+    .label_style(Style::default().fg(FOREGROUND));
+
+  frame.render_widget(chart, area);
+}
+
+pub fn charts_ui<B: Backend>(frame: &mut Frame<B>, app: &App, area: Rect) {
+  let layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints([Constraint::Percentage(60), Constraint::Percentage(40)].as_ref())
+    .split(area);
+
+  trends_chart(frame, app, layout[0]);
+  categories_chart(frame, app, layout[1]);
+}
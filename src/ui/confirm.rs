@@ -0,0 +1,35 @@
+use tui::{
+  backend::Backend,
+  layout::Alignment,
+  style::Style,
+  text::{Spans, Span},
+  widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap},
+  Frame,
+};
+
+use super::{colors::*, popup::centered_rect};
+
+pub fn confirm_ui<B: Backend>(frame: &mut Frame<B>, title: &str, message: String) {
+  let area = centered_rect(40, 7, frame.size());
+
+  let block = Block::default()
+    .title(title.to_string())
+    .borders(Borders::ALL)
+    .border_type(BorderType::Thick)
+    .style(Style::default().bg(BACKGROUND).fg(RED));
+
+  let paragraph = Paragraph::new(vec![
+    Spans::from(Span::styled(message, Style::default().fg(FOREGROUND))),
+    Spans::from(""),
+    Spans::from(Span::styled(
+      "y/Enter: confirm | n/Esc: cancel",
+      Style::default().fg(CYAN),
+    )),
+  ])
+  .block(block)
+  .alignment(Alignment::Center)
+  .wrap(Wrap { trim: true });
+
+  frame.render_widget(Clear, area);
+  frame.render_widget(paragraph, area);
+}
@@ -0,0 +1,84 @@
+use tui::{
+  backend::Backend,
+  layout::{Alignment, Constraint, Direction, Layout},
+  style::Style,
+  text::{Span, Spans},
+  widgets::{Block, Borders, Clear, Paragraph, Wrap},
+  Frame,
+};
+
+use crate::{enums::export_field::ExportField, states::export::ExportState};
+
+use super::{
+  colors::*,
+  inputs::{create_input, create_select, set_input_cursor},
+  popup::centered_rect,
+};
+
+pub fn export_ui<B: Backend>(frame: &mut Frame<B>, export: &ExportState) {
+  let area = centered_rect(50, 12, frame.size());
+
+  let block = Block::default()
+    .title(format!("Export {}", export.period))
+    .borders(Borders::ALL)
+    .style(Style::default().bg(BACKGROUND).fg(ORANGE));
+
+  let inner = block.inner(area);
+
+  frame.render_widget(Clear, area);
+  frame.render_widget(block, area);
+
+  let layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(
+      [
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(2),
+        Constraint::Length(1),
+      ]
+      .as_ref(),
+    )
+    .split(inner);
+
+  frame.render_widget(
+    create_select(
+      "Format",
+      format!("{:?}", export.format),
+      export.selected_field == ExportField::Format,
+    ),
+    layout[0],
+  );
+
+  frame.render_widget(
+    create_input(
+      "File",
+      &export.path,
+      export.selected_field == ExportField::Path,
+      false,
+      layout[1].width,
+    ),
+    layout[1],
+  );
+
+  if let Some(error) = &export.error {
+    let error_paragraph = Paragraph::new(Spans::from(Span::styled(
+      error.clone(),
+      Style::default().fg(RED),
+    )))
+    .wrap(Wrap { trim: true });
+
+    frame.render_widget(error_paragraph, layout[2]);
+  }
+
+  let help = Paragraph::new(Spans::from(Span::styled(
+    "Tab: next field | Enter: export | Esc: cancel",
+    Style::default().fg(CYAN),
+  )))
+  .alignment(Alignment::Center);
+  frame.render_widget(help, layout[3]);
+
+  if export.selected_field == ExportField::Path {
+    set_input_cursor(frame, &export.path, layout[1]);
+  }
+}
@@ -0,0 +1,121 @@
+use tui::{
+  backend::Backend,
+  layout::{Alignment, Constraint, Direction, Layout},
+  style::Style,
+  text::{Span, Spans},
+  widgets::{Block, Borders, Clear, Paragraph, Wrap},
+  Frame,
+};
+
+use crate::{enums::filter_field::FilterField, states::filter_form::FilterFormState};
+
+use super::{
+  colors::*,
+  inputs::{create_input, create_select, set_input_cursor},
+  popup::centered_rect,
+};
+
+pub fn filter_form_ui<B: Backend>(frame: &mut Frame<B>, form: &FilterFormState) {
+  let area = centered_rect(60, 15, frame.size());
+
+  let block = Block::default()
+    .title("Filter transactions")
+    .borders(Borders::ALL)
+    .style(Style::default().bg(BACKGROUND).fg(BLUE));
+
+  let inner = block.inner(area);
+
+  frame.render_widget(Clear, area);
+  frame.render_widget(block, area);
+
+  let layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(
+      [
+        Constraint::Length(9),
+        Constraint::Length(2),
+        Constraint::Length(1),
+      ]
+      .as_ref(),
+    )
+    .split(inner);
+
+  let columns = Layout::default()
+    .direction(Direction::Horizontal)
+    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+    .split(layout[0]);
+
+  let field_constraints = [
+    Constraint::Length(3),
+    Constraint::Length(3),
+    Constraint::Length(3),
+  ];
+
+  let left = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(field_constraints.as_ref())
+    .split(columns[0]);
+
+  let right = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(field_constraints.as_ref())
+    .split(columns[1]);
+
+  let is_selected = |field: FilterField| form.selected_field == field;
+
+  let inputs = [
+    ("Text", &form.text, FilterField::Text, left[0]),
+    ("Category", &form.category, FilterField::Category, left[1]),
+    ("Day (5 or 1-15)", &form.days, FilterField::Days, left[2]),
+    ("Min amount", &form.min, FilterField::Min, right[0]),
+    ("Max amount", &form.max, FilterField::Max, right[1]),
+  ];
+
+  for (title, input, field, area) in inputs {
+    frame.render_widget(
+      create_input(title, input, is_selected(field), false, area.width),
+      area,
+    );
+  }
+
+  frame.render_widget(
+    create_select(
+      "Currency",
+      match &form.currency {
+        Some(currency) => currency.to_string(),
+        None => String::from("Any"),
+      },
+      is_selected(FilterField::Currency),
+    ),
+    right[2],
+  );
+
+  if let Some(error) = &form.error {
+    let error_paragraph = Paragraph::new(Spans::from(Span::styled(
+      error.clone(),
+      Style::default().fg(RED),
+    )))
+    .wrap(Wrap { trim: true });
+
+    frame.render_widget(error_paragraph, layout[1]);
+  }
+
+  let help = Paragraph::new(Spans::from(Span::styled(
+    "Tab: next field | Left/Right: change currency | Enter: apply | Esc: cancel",
+    Style::default().fg(CYAN),
+  )))
+  .alignment(Alignment::Center);
+  frame.render_widget(help, layout[2]);
+
+  if let Some(input) = form.selected_input() {
+    let input_area = match form.selected_field {
+      FilterField::Text => left[0],
+      FilterField::Category => left[1],
+      FilterField::Days => left[2],
+      FilterField::Min => right[0],
+      _ => right[1],
+    };
+
+    set_input_cursor(frame, input, input_area);
+  }
+}
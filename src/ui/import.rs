@@ -0,0 +1,181 @@
+use tui::{
+  backend::Backend,
+  layout::{Alignment, Constraint, Direction, Layout},
+  style::Style,
+  text::{Span, Spans},
+  widgets::{Block, Borders, Clear, Paragraph, Wrap},
+  Frame,
+};
+
+use crate::{
+  enums::{import_field::ImportField, import_step::ImportStep},
+  states::import::ImportState,
+};
+
+use super::{
+  colors::*,
+  inputs::{create_input, create_select, set_input_cursor},
+  popup::centered_rect,
+  tables::{create_import_table, import_widths},
+};
+
+pub fn import_ui<B: Backend>(frame: &mut Frame<B>, import: &mut ImportState) {
+  let size = frame.size();
+  let area = centered_rect(80, size.height.saturating_sub(4), size);
+
+  let block = Block::default()
+    .title(match import.step {
+      ImportStep::File => "Import CSV",
+      ImportStep::Mapping => "Import CSV - map columns",
+      ImportStep::Preview => "Import CSV - preview",
+    })
+    .borders(Borders::ALL)
+    .style(Style::default().bg(BACKGROUND).fg(ORANGE));
+
+  let inner = block.inner(area);
+
+  frame.render_widget(Clear, area);
+  frame.render_widget(block, area);
+
+  let layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(
+      [
+        Constraint::Min(0),
+        Constraint::Length(2),
+        Constraint::Length(1),
+      ]
+      .as_ref(),
+    )
+    .split(inner);
+
+  let help = match import.step {
+    ImportStep::File => "Enter: load file | Esc: cancel",
+    ImportStep::Mapping => "Tab: next field | Left/Right: change | Enter: preview | Esc: back",
+    ImportStep::Preview => "Up/Down: move | Space: toggle row | Enter: import | Esc: back",
+  };
+
+  match import.step {
+    ImportStep::File => {
+      let file_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
+        .split(layout[0]);
+
+      frame.render_widget(
+        create_input("CSV file", &import.path, true, false, file_layout[0].width),
+        file_layout[0],
+      );
+      set_input_cursor(frame, &import.path, file_layout[0]);
+    }
+    ImportStep::Mapping => {
+      let mapping_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+          [
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(0),
+          ]
+          .as_ref(),
+        )
+        .split(layout[0]);
+
+      let fields = [
+        (
+          "Date",
+          import.column_name(import.date_column),
+          ImportField::Date,
+        ),
+        (
+          "Amount",
+          import.column_name(import.amount_column),
+          ImportField::Amount,
+        ),
+        ("Currency", import.currency_name(), ImportField::Currency),
+        (
+          "Note",
+          import.column_name(import.note_column),
+          ImportField::Note,
+        ),
+        (
+          "Type",
+          format!("{:?}", import.transaction_type),
+          ImportField::Type,
+        ),
+      ];
+
+      for (index, (title, value, field)) in fields.into_iter().enumerate() {
+        frame.render_widget(
+          create_select(title, value, import.selected_field == field),
+          mapping_layout[index],
+        );
+      }
+
+      let sample: Vec<Spans> = import
+        .records
+        .iter()
+        .take(3)
+        .map(|record| {
+          Spans::from(Span::styled(
+            record.join(" | "),
+            Style::default().fg(FOREGROUND),
+          ))
+        })
+        .collect();
+
+      frame.render_widget(
+        Paragraph::new(sample)
+          .block(Block::default().title("First rows").borders(Borders::ALL))
+          .wrap(Wrap { trim: true }),
+        mapping_layout[5],
+      );
+    }
+    ImportStep::Preview => {
+      let preview_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)].as_ref())
+        .split(layout[0]);
+
+      let (new, duplicates, errors) = import.summary();
+
+      frame.render_widget(
+        Paragraph::new(Spans::from(vec![
+          Span::styled(format!("{} new", new), Style::default().fg(GREEN)),
+          Span::raw(" | "),
+          Span::styled(
+            format!("{} duplicates", duplicates),
+            Style::default().fg(YELLOW),
+          ),
+          Span::raw(" | "),
+          Span::styled(format!("{} errors", errors), Style::default().fg(RED)),
+        ])),
+        preview_layout[0],
+      );
+
+      let widths = import_widths(preview_layout[1].width);
+      let table = create_import_table(&import.table, &widths);
+      frame.render_stateful_widget(table, preview_layout[1], &mut import.table.state);
+    }
+  }
+
+  if let Some(error) = &import.error {
+    frame.render_widget(
+      Paragraph::new(Spans::from(Span::styled(
+        error.clone(),
+        Style::default().fg(RED),
+      )))
+      .wrap(Wrap { trim: true }),
+      layout[1],
+    );
+  }
+
+  frame.render_widget(
+    Paragraph::new(Spans::from(Span::styled(help, Style::default().fg(CYAN))))
+      .alignment(Alignment::Center),
+    layout[2],
+  );
+}
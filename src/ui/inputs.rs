@@ -0,0 +1,69 @@
+use tui::{
+  backend::Backend,
+  layout::Rect,
+  style::Style,
+  widgets::{Block, Borders, BorderType, Paragraph},
+  Frame,
+};
+use tui_input::Input;
+
+use super::colors::*;
+
+pub fn create_input<'a>(
+  title: &'a str,
+  input: &'a Input,
+  is_selected: bool,
+  is_secret: bool,
+  width: u16,
+) -> Paragraph<'a> {
+  let scroll = input.visual_scroll(width.saturating_sub(3) as usize);
+
+  let value = if is_secret {
+    "*".repeat(input.value().chars().count())
+  } else {
+    input.value().to_string()
+  };
+
+  Paragraph::new(value).scroll((0, scroll as u16)).block(
+    Block::default()
+      .title(title)
+      .borders(Borders::ALL)
+      .border_type(if is_selected {
+        BorderType::Thick
+      } else {
+        BorderType::Plain
+      })
+      .style(
+        Style::default()
+          .bg(BACKGROUND)
+          .fg(if is_selected { YELLOW } else { FOREGROUND }),
+      ),
+  )
+}
+
+pub fn set_input_cursor<B: Backend>(frame: &mut Frame<B>, input: &Input, area: Rect) {
+  let scroll = input.visual_scroll(area.width.saturating_sub(3) as usize);
+
+  frame.set_cursor(
+    area.x + (input.visual_cursor().max(scroll) - scroll) as u16 + 1,
+    area.y + 1,
+  );
+}
+
+pub fn create_select<'a>(title: &'a str, value: String, is_selected: bool) -> Paragraph<'a> {
+  Paragraph::new(format!("< {} >", value)).block(
+    Block::default()
+      .title(title)
+      .borders(Borders::ALL)
+      .border_type(if is_selected {
+        BorderType::Thick
+      } else {
+        BorderType::Plain
+      })
+      .style(
+        Style::default()
+          .bg(BACKGROUND)
+          .fg(if is_selected { YELLOW } else { FOREGROUND }),
+      ),
+  )
+}
@@ -0,0 +1,140 @@
+use rust_decimal::Decimal;
+use tui::{
+  backend::Backend,
+  layout::{Constraint, Direction, Layout, Rect},
+  style::Style,
+  widgets::{Cell, Row, Table},
+  Frame,
+};
+
+use crate::{
+  enums::selected_block::SelectedBlock,
+  states::App,
+  types::{money::Money, year_month::YearMonth},
+  utils::installments::{upcoming, InstallmentPlan},
+};
+
+use super::{blocks::create_block, colors::*};
+
+const PLAN_WIDTHS: [Constraint; 5] = [
+  Constraint::Percentage(28),
+  Constraint::Percentage(20),
+  Constraint::Percentage(12),
+  Constraint::Percentage(24),
+  Constraint::Percentage(16),
+];
+
+const UPCOMING_WIDTHS: [Constraint; 3] = [
+  Constraint::Percentage(30),
+  Constraint::Percentage(15),
+  Constraint::Percentage(55),
+];
+
+fn plan_row<'a>(plan: &InstallmentPlan, now: &YearMonth, app: &App) -> Row<'a> {
+  let payment = Money::new(plan.payment, plan.currency.clone());
+  let remaining = plan.remaining(now);
+
+  let color = if remaining == 0 { GREEN } else { FOREGROUND };
+
+  Row::new(vec![
+    Cell::from(plan.name.clone()),
+    Cell::from(payment.format(&app.language)),
+    Cell::from(format!("{}/{}", plan.paid(now), plan.count)),
+    Cell::from(match remaining {
+      0 => String::from("paid off"),
+      _ => plan.remaining_amount(now).format(&app.language),
+    }),
+    Cell::from(plan.end().to_string()),
+  ])
+  .style(Style::default().fg(color))
+}
+
+/// Sum of a month's projected charges in the display currency.
+fn charges_total(charges: &[&InstallmentPlan], app: &App) -> String {
+  let mut total = Decimal::ZERO;
+  let mut missing = 0;
+
+  for plan in charges {
+    match app
+      .converter
+      .convert(plan.payment, &plan.currency, plan.exchange_rate)
+    {
+      Some(amount) => total += amount,
+      None => missing += 1,
+    }
+  }
+
+  let total = Money::new(total, app.converter.display.clone()).format(&app.language);
+
+  match missing {
+    0 => total,
+    missing => format!("{} ({} without rate)", total, missing),
+  }
+}
+
+pub fn installments_ui<B: Backend>(frame: &mut Frame<B>, app: &mut App, area: Rect) {
+  let layout = Layout::default()
+    .direction(Direction::Horizontal)
+    .constraints([Constraint::Percentage(65), Constraint::Percentage(35)].as_ref())
+    .split(area);
+
+  let now = YearMonth::now();
+  let header_style = Style::default().fg(BACKGROUND).bg(ORANGE);
+
+  let title = if app.installments.loading {
+    String::from("Installment plans loading...")
+  } else {
+    String::from("Installment plans")
+  };
+
+  let rows: Vec<Row> = app
+    .installments
+    .plans
+    .iter()
+    .map(|plan| plan_row(plan, &now, app))
+    .collect();
+
+  let plans = Table::new(rows)
+    .header(
+      Row::new(["Plan", "Payment", "Paid", "Remaining", "Ends"])
+        .style(header_style)
+        .bottom_margin(1),
+    )
+    .block(create_block(
+      title,
+      &app.selected_block,
+      SelectedBlock::Installments,
+      ORANGE,
+    ))
+    .widths(&PLAN_WIDTHS)
+    .highlight_style(Style::default().bg(GREY).fg(YELLOW));
+
+  let upcoming_rows: Vec<Row> = upcoming(&app.installments.plans, &now)
+    .into_iter()
+    .map(|(period, charges)| {
+      Row::new(vec![
+        Cell::from(period.to_string()),
+        Cell::from(charges.len().to_string()),
+        Cell::from(charges_total(&charges, app)),
+      ])
+    })
+    .collect();
+
+  let upcoming = Table::new(upcoming_rows)
+    .header(
+      Row::new(["Month", "Charges", "Total"])
+        .style(header_style)
+        .bottom_margin(1),
+    )
+    .block(create_block(
+      format!("Upcoming ({})", app.converter.display),
+      &app.selected_block,
+      SelectedBlock::Installments,
+      ORANGE,
+    ))
+    .widths(&UPCOMING_WIDTHS)
+    .style(Style::default().fg(FOREGROUND));
+
+  frame.render_stateful_widget(plans, layout[0], &mut app.installments.table);
+  frame.render_widget(upcoming, layout[1]);
+}
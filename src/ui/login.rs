@@ -0,0 +1,94 @@
+use tui::{
+  backend::Backend,
+  layout::{Layout, Direction, Constraint, Alignment},
+  style::Style,
+  text::{Spans, Span},
+  widgets::{Block, Borders, Clear, Paragraph, Wrap},
+  Frame,
+};
+
+use crate::{enums::login_field::LoginField, states::login::LoginState};
+
+use super::{
+  colors::*,
+  inputs::{create_input, set_input_cursor},
+  popup::centered_rect,
+};
+
+pub fn login_ui<B: Backend>(frame: &mut Frame<B>, state: &LoginState) {
+  let background = Block::default().style(Style::default().bg(BACKGROUND));
+  frame.render_widget(background, frame.size());
+
+  let area = centered_rect(50, 14, frame.size());
+
+  let block = Block::default()
+    .title("Login")
+    .borders(Borders::ALL)
+    .style(Style::default().bg(BACKGROUND).fg(ORANGE));
+
+  let inner = block.inner(area);
+
+  frame.render_widget(Clear, area);
+  frame.render_widget(block, area);
+
+  let layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(
+      [
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(1),
+      ]
+      .as_ref(),
+    )
+    .split(inner);
+
+  let email = create_input(
+    "Email",
+    &state.email,
+    state.selected_field == LoginField::Email,
+    false,
+    layout[0].width,
+  );
+  frame.render_widget(email, layout[0]);
+
+  let password = create_input(
+    "Password",
+    &state.password,
+    state.selected_field == LoginField::Password,
+    true,
+    layout[1].width,
+  );
+  frame.render_widget(password, layout[1]);
+
+  if let Some(error) = &state.error {
+    let error_paragraph = Paragraph::new(Spans::from(Span::styled(
+      error.clone(),
+      Style::default().fg(RED),
+    )))
+    .wrap(Wrap { trim: true });
+
+    frame.render_widget(error_paragraph, layout[2]);
+  } else if let Some(info) = &state.info {
+    let info_paragraph = Paragraph::new(Spans::from(Span::styled(
+      info.clone(),
+      Style::default().fg(GREEN),
+    )))
+    .wrap(Wrap { trim: true });
+
+    frame.render_widget(info_paragraph, layout[2]);
+  }
+
+  let help = Paragraph::new(Spans::from(Span::styled(
+    "Tab: next field | Enter: login | F2: sign up | Esc: quit",
+    Style::default().fg(CYAN),
+  )))
+  .alignment(Alignment::Center);
+  frame.render_widget(help, layout[3]);
+
+  match state.selected_field {
+    LoginField::Email => set_input_cursor(frame, &state.email, layout[0]),
+    LoginField::Password => set_input_cursor(frame, &state.password, layout[1]),
+  }
+}
@@ -1,12 +1,27 @@
-use std::{time::Duration, error};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 use crate::{
-  load_config,
+  save_config,
   models::{custom_error::CustomError, transaction::TransactionModel},
-  enums::{transaction_type::TransactionType, selected_block::SelectedBlock},
-  utils::{transaction_utils, StatefulTree},
-  states::{App, DataTable},
+  enums::{
+    transaction_type::TransactionType, selected_block::SelectedBlock, login_field::LoginField,
+    delete_target::DeleteTarget, language::Language, tab::Tab,
+  },
+  utils::{
+    cache::{Cache, CachedMonth},
+    currency::Converter,
+    StatefulTree,
+  },
+  states::{
+    App, DataTable,
+    loader::{fetch_month, Loader, MonthData},
+    login::LoginState,
+    sign_up::SignUpState,
+  },
+  types::{money::Money, responses::login::LoginResponse, year_month::YearMonth, Config},
 };
 use crossterm::event::{KeyCode, self, Event, poll};
+use chrono::Local;
+use tokio::sync::mpsc;
 use tui::{
   Terminal,
   backend::Backend,
@@ -16,24 +31,65 @@ use tui::{
   text::{Spans, Span},
 };
 use tui_tree_widget::{Tree, TreeItem};
+use tui_input::Input;
 
-use crate::requests::transaction;
+use crate::{
+  requests::api_client::ApiClient,
+  storage::{http::HttpStorage, local::LocalStorage, SharedStorage, Storage},
+};
 
 use self::{
   blocks::create_block,
-  tables::{create_expenses_table, create_savings_table, create_incomes_table},
+  tables::{
+    create_expenses_table, create_savings_table, create_incomes_table, create_categories_table,
+    transactions_widths,
+  },
   summary::create_summeries,
   colors::*,
   tabs::create_tabs,
   paragraph::get_paragraph_to_details_transaction_details,
+  login::login_ui,
+  sign_up::sign_up_ui,
+  transaction_form::transaction_form_ui,
+  confirm::confirm_ui,
+  category_form::category_form_ui,
+  status_bar::status_bar_ui,
+  import::import_ui,
+  export::export_ui,
+  filter_form::filter_form_ui,
+  budgets::budgets_ui,
+  budget_form::budget_form_ui,
+  charts::charts_ui,
+  installments::installments_ui,
+  recurring::{recurring_prompt_ui, recurring_ui},
+  recurring_form::recurring_form_ui,
+  year_overview::year_overview_ui,
 };
 
 pub mod blocks;
+pub mod budget_form;
+pub mod budgets;
+pub mod category_form;
+pub mod charts;
 pub mod colors;
+pub mod confirm;
+pub mod export;
+pub mod filter_form;
+pub mod import;
+pub mod inputs;
+pub mod installments;
+pub mod login;
 pub mod paragraph;
+pub mod popup;
+pub mod recurring;
+pub mod recurring_form;
+pub mod sign_up;
+pub mod status_bar;
 pub mod summary;
 pub mod tables;
 pub mod tabs;
+pub mod transaction_form;
+pub mod year_overview;
 
 type TransactionsTuple = (
   Vec<TransactionModel>,
@@ -41,75 +97,92 @@ type TransactionsTuple = (
   Vec<TransactionModel>,
 );
 
-pub async fn get_transactions<'a>(
-  client: &reqwest::Client,
-  token: &String,
-  tree: &StatefulTree<'a>,
-) -> Result<TransactionsTuple, Box<dyn error::Error>> {
-  let mut expenses = transaction::get_transactions_by_month_and_type(
-    client,
-    token,
-    TransactionType::EXPENSE,
-    tree.current_month.clone(),
-  )
-  .await?;
-
-  let installments = transaction::get_transactions_by_month_and_type(
-    client,
-    token,
-    TransactionType::INSTALLMENTS,
-    tree.current_month.clone(),
-  )
-  .await?;
-
-  expenses.extend(installments);
-
-  let incomes = transaction::get_transactions_by_month_and_type(
-    client,
-    token,
-    TransactionType::INCOME,
-    tree.current_month.clone(),
-  )
-  .await?;
-
-  let savings = transaction::get_transactions_by_month_and_type(
-    client,
-    token,
-    TransactionType::SAVING,
-    tree.current_month.clone(),
-  )
-  .await?;
-
-  Ok((expenses, incomes, savings))
+pub async fn get_transactions(
+  storage: &dyn Storage,
+  period: &YearMonth,
+) -> Result<TransactionsTuple, CustomError> {
+  let (expenses, installments, incomes, savings) = tokio::join!(
+    storage.get_transactions_by_month_and_type(TransactionType::EXPENSE, period),
+    storage.get_transactions_by_month_and_type(TransactionType::INSTALLMENTS, period),
+    storage.get_transactions_by_month_and_type(TransactionType::INCOME, period),
+    storage.get_transactions_by_month_and_type(TransactionType::SAVING, period),
+  );
+
+  let mut expenses = expenses?;
+  expenses.extend(installments?);
+
+  Ok((expenses, incomes?, savings?))
 }
 
-pub async fn run_app<B: Backend>(
+async fn run_login<B: Backend>(
   terminal: &mut Terminal<B>,
-  client: &reqwest::Client,
-) -> Result<(), Box<dyn error::Error>> {
-  let config = match load_config() {
-    Ok(config) => config,
-    Err(_) => {
-      return Err(Box::new(CustomError::new(
-        Some(""),
-        Some(String::from("Error to load config")),
-        None,
-      )));
+  api: &ApiClient,
+  language: &Language,
+  error: Option<String>,
+) -> Result<Option<LoginResponse>, CustomError> {
+  let mut login = LoginState::new(api.to_owned(), language.clone());
+  login.error = error;
+
+  let mut sign_up: Option<SignUpState> = None;
+
+  loop {
+    terminal.draw(|f: &mut Frame<B>| match &sign_up {
+      Some(sign_up) => sign_up_ui(f, sign_up),
+      None => login_ui(f, &login),
+    })?;
+
+    if poll(Duration::from_millis(100))? {
+      if let Event::Key(event) = event::read()? {
+        match &mut sign_up {
+          Some(state) => {
+            if event.code == KeyCode::Esc {
+              sign_up = None;
+              continue;
+            }
+
+            state.process_key_event(event.code).await;
+
+            if let Some(user) = &state.user {
+              login.email = Input::new(user.email.clone());
+              login.password.reset();
+              login.selected_field = LoginField::Password;
+              login.error = None;
+              login.info = Some(String::from("Account created, you can login now"));
+              sign_up = None;
+            }
+          }
+          None => match event.code {
+            KeyCode::Esc => return Ok(None),
+            KeyCode::F(2) => sign_up = Some(SignUpState::new(api.to_owned(), language.clone())),
+            _ => login.process_key_event(event.code).await,
+          },
+        }
+      }
     }
-  };
 
-  let token = match config.session_id {
-    Some(token) => token,
-    None => {
-      return Err(Box::new(CustomError::new(
-        Some(""),
-        Some(String::from("Error to load user token")),
-        None,
-      )))
+    if login.session.is_some() {
+      return Ok(login.session);
     }
-  };
+  }
+}
 
-  let months_by_year = transaction::get_month_by_year(client, &token).await?;
+async fn run_transactions<B: Backend>(
+  terminal: &mut Terminal<B>,
+  storage: SharedStorage,
+  config: &Config,
+  offline: bool,
+  mut cache: Cache,
+) -> Result<(), CustomError> {
+  let (months_by_year, categories) = if offline {
+    (
+      cache.data.months_by_year.clone(),
+      cache.data.categories.clone(),
+    )
+  } else {
+    let (months_by_year, categories) =
+      tokio::join!(storage.get_month_by_year(), storage.get_categories());
+    (months_by_year?, categories?)
+  };
 
   let mut tree_items: Vec<TreeItem> = Vec::new();
 
@@ -123,51 +196,145 @@ pub async fn run_app<B: Backend>(
     tree_items.push(TreeItem::new(item.year.clone(), months));
   }
 
-  let tree = StatefulTree::with_items(tree_items.clone());
-
-  let mut transactions = get_transactions(client, &token, &tree).await?;
-
-  transaction_utils::sort(&mut transactions.0);
-  transaction_utils::sort(&mut transactions.1);
-  transaction_utils::sort(&mut transactions.2);
-
-  let summary =
-    transaction::get_transactions_balances(client, &token, tree.current_month.clone()).await?;
-
-  let total_saving = transaction::get_total_saving(client, &token).await?;
+  let mut tree = StatefulTree::with_items(tree_items.clone());
+
+  let month = if offline {
+    let cached = match cache.month(&tree.current) {
+      Some(month) => Some(month.clone()),
+      None => cache.latest_month().map(|(period, month)| {
+        tree.current = period;
+        month.clone()
+      }),
+    };
+
+    cached.unwrap_or_else(|| CachedMonth {
+      data: MonthData::default(),
+      fetched_at: Local::now(),
+    })
+  } else {
+    let month = fetch_month(storage.as_ref(), &tree.current).await?;
+
+    cache.data.months_by_year = months_by_year.clone();
+    cache.data.categories = categories.clone();
+    cache.store_month(&tree.current, month.clone());
+    cache.save()?;
+
+    CachedMonth {
+      data: month,
+      fetched_at: Local::now(),
+    }
+  };
 
   let data: DataTable = DataTable {
     months_by_year,
-    expenses: transactions.0,
-    incomes: transactions.1,
-    savings: transactions.2,
-    summary,
+    expenses: month.data.expenses,
+    incomes: month.data.incomes,
+    savings: month.data.savings,
+    summary: month.data.summary,
     tree,
-    total_saving,
+    total_saving: month.data.total_saving,
+    categories,
+    refreshed_at: month.fetched_at,
   };
 
-  let mut app: App = App::new(data, token, client.to_owned());
+  let (sender, mut receiver) = mpsc::unbounded_channel();
+  let mut app: App = App::new(
+    data,
+    storage,
+    config.language.clone(),
+    Converter::from_config(config),
+    Loader::new(sender),
+    cache,
+    offline,
+  );
   loop {
     terminal.draw(|f: &mut Frame<B>| ui(f, &mut app))?;
 
+    app.tick();
+
+    while let Ok(message) = receiver.try_recv() {
+      app.process_message(message);
+    }
+
     if poll(Duration::from_millis(100))? {
       if let Event::Key(event) = event::read()? {
-        if event.code == KeyCode::Char('q') {
+        if event.code == KeyCode::Char('q') && !app.is_input_mode() {
           return Ok(());
         }
-        app.process_key_event(event.code).await;
+        app.process_key_event(event.code);
       }
     }
+
+    if app.session_expired {
+      return Err(CustomError::Unauthorized);
+    }
   }
 }
 
+pub async fn run_app<B: Backend>(
+  terminal: &mut Terminal<B>,
+  mut config: Config,
+  mut api: ApiClient,
+  mut online: bool,
+) -> Result<(), CustomError> {
+  let mut login_error: Option<String> = None;
+
+  loop {
+    if config.session_id.is_none() && online {
+      match run_login(terminal, &api, &config.language, login_error.take()).await? {
+        Some(session) => {
+          config.session_id = Some(session.token);
+          config.user_id = Some(session.user.user_id);
+          save_config(&config)?;
+        }
+        None => return Ok(()),
+      }
+    }
+
+    api.set_session(config.session_id.clone());
+
+    let storage: SharedStorage = Arc::new(HttpStorage::new(api.clone()));
+
+    match run_transactions(terminal, storage, &config, !online, Cache::load(&config)).await {
+      Err(err) if err.is_unauthorized() => {
+        online = true;
+        config.session_id = None;
+        api.set_session(None);
+        save_config(&config)?;
+        login_error = Some(err.message(&config.language));
+      }
+      result => return result,
+    }
+  }
+}
+
+/// Runs the app against the local file store, no server or login involved.
+pub async fn run_local<B: Backend>(
+  terminal: &mut Terminal<B>,
+  config: &Config,
+) -> Result<(), CustomError> {
+  let path = match &config.data_path {
+    Some(path) => PathBuf::from(path),
+    None => LocalStorage::default_path(),
+  };
+
+  let storage: SharedStorage = Arc::new(LocalStorage::open(path)?);
+
+  run_transactions(terminal, storage, config, false, Cache::disabled()).await
+}
+
 pub fn ui<B: Backend>(frame: &mut Frame<B>, app: &mut App) {
-  let size = frame.size();
+  let main_layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints([Constraint::Min(0), Constraint::Length(1)].as_ref())
+    .split(frame.size());
+
+  status_bar_ui(frame, app, main_layout[1]);
 
   let chunks = Layout::default()
     .direction(Direction::Horizontal)
     .constraints([Constraint::Percentage(20), Constraint::Percentage(80)].as_ref())
-    .split(size);
+    .split(main_layout[0]);
 
   let months_block = Tree::new(app.tree.items.clone())
     .block(create_block(
@@ -237,47 +404,54 @@ pub fn ui<B: Backend>(frame: &mut Frame<B>, app: &mut App) {
     .constraints([Constraint::Percentage(80), Constraint::Percentage(20)].as_ref())
     .split(transactions_layout[1]);
 
-  let expenses_table = create_expenses_table(app);
-  let incomes_table = create_incomes_table(app);
-  let saving_table = create_savings_table(app);
-
-  match app.tabs.index {
-    0 => {
+  match app.tabs.current() {
+    Tab::All => {
+      let expenses_widths = transactions_widths(transactions_layout[0].width);
+      let expenses_table = create_expenses_table(app, &expenses_widths);
       frame.render_stateful_widget(
         expenses_table,
         transactions_layout[0],
         &mut app.table_state.expenses.state,
       );
 
+      let incomes_widths = transactions_widths(transactions_icomes_layout[0].width);
+      let incomes_table = create_incomes_table(app, &incomes_widths);
       frame.render_stateful_widget(
         incomes_table,
         transactions_icomes_layout[0],
         &mut app.table_state.incomes.state,
       );
 
+      let savings_widths = transactions_widths(transactions_icomes_layout[1].width);
+      let saving_table = create_savings_table(app, &savings_widths);
       frame.render_stateful_widget(
         saving_table,
         transactions_icomes_layout[1],
         &mut app.table_state.savings.state,
       );
     }
-    1 => {
+    Tab::Expenses => {
       if app.details_shown {
-        match &app.table_state.transactions_details {
-          Some(transaction) => {
-            let paragraph =
-              get_paragraph_to_details_transaction_details(&app.selected_block, transaction, RED);
-            frame.render_widget(paragraph, transactions_layout[1]);
-
-            frame.render_stateful_widget(
-              expenses_table,
-              transactions_layout[0],
-              &mut app.table_state.expenses.state,
-            );
-          }
-          None => (),
+        if let Some(transaction) = &app.table_state.transactions_details {
+          let paragraph = get_paragraph_to_details_transaction_details(
+            &app.selected_block,
+            transaction,
+            &app.language,
+            RED,
+          );
+          frame.render_widget(paragraph, transactions_layout[1]);
+
+          let widths = transactions_widths(transactions_layout[0].width);
+          let expenses_table = create_expenses_table(app, &widths);
+          frame.render_stateful_widget(
+            expenses_table,
+            transactions_layout[0],
+            &mut app.table_state.expenses.state,
+          );
         }
       } else {
+        let widths = transactions_widths(tabs_layout[1].width);
+        let expenses_table = create_expenses_table(app, &widths);
         frame.render_stateful_widget(
           expenses_table,
           tabs_layout[1],
@@ -285,24 +459,29 @@ pub fn ui<B: Backend>(frame: &mut Frame<B>, app: &mut App) {
         );
       }
     }
-    2 => {
+    Tab::Incomes => {
       if app.details_shown {
-        match &app.table_state.transactions_details {
-          Some(transaction) => {
-            let paragraph =
-              get_paragraph_to_details_transaction_details(&app.selected_block, transaction, GREEN);
-
-            frame.render_widget(paragraph, transactions_layout[1]);
-
-            frame.render_stateful_widget(
-              incomes_table,
-              transactions_layout[0],
-              &mut app.table_state.incomes.state,
-            );
-          }
-          None => (),
+        if let Some(transaction) = &app.table_state.transactions_details {
+          let paragraph = get_paragraph_to_details_transaction_details(
+            &app.selected_block,
+            transaction,
+            &app.language,
+            GREEN,
+          );
+
+          frame.render_widget(paragraph, transactions_layout[1]);
+
+          let widths = transactions_widths(transactions_layout[0].width);
+          let incomes_table = create_incomes_table(app, &widths);
+          frame.render_stateful_widget(
+            incomes_table,
+            transactions_layout[0],
+            &mut app.table_state.incomes.state,
+          );
         }
       } else {
+        let widths = transactions_widths(tabs_layout[1].width);
+        let incomes_table = create_incomes_table(app, &widths);
         frame.render_stateful_widget(
           incomes_table,
           tabs_layout[1],
@@ -310,27 +489,29 @@ pub fn ui<B: Backend>(frame: &mut Frame<B>, app: &mut App) {
         );
       }
     }
-    3 => {
+    Tab::Savings => {
       if app.details_shown {
-        match &app.table_state.transactions_details {
-          Some(transaction) => {
-            let paragraph = get_paragraph_to_details_transaction_details(
-              &app.selected_block,
-              transaction,
-              FOREGROUND,
-            );
-
-            frame.render_widget(paragraph, transactions_layout[1]);
-
-            frame.render_stateful_widget(
-              saving_table,
-              transactions_layout[0],
-              &mut app.table_state.savings.state,
-            );
-          }
-          None => (),
+        if let Some(transaction) = &app.table_state.transactions_details {
+          let paragraph = get_paragraph_to_details_transaction_details(
+            &app.selected_block,
+            transaction,
+            &app.language,
+            FOREGROUND,
+          );
+
+          frame.render_widget(paragraph, transactions_layout[1]);
+
+          let widths = transactions_widths(transactions_layout[0].width);
+          let saving_table = create_savings_table(app, &widths);
+          frame.render_stateful_widget(
+            saving_table,
+            transactions_layout[0],
+            &mut app.table_state.savings.state,
+          );
         }
       } else {
+        let widths = transactions_widths(tabs_layout[1].width);
+        let saving_table = create_savings_table(app, &widths);
         frame.render_stateful_widget(
           saving_table,
           tabs_layout[1],
@@ -338,6 +519,89 @@ pub fn ui<B: Backend>(frame: &mut Frame<B>, app: &mut App) {
         );
       }
     }
-    _ => (),
+    Tab::Categories => {
+      let categories_table = create_categories_table(app);
+      frame.render_stateful_widget(
+        categories_table,
+        tabs_layout[1],
+        &mut app.categories_table.state,
+      );
+    }
+    Tab::Budgets => budgets_ui(frame, app, tabs_layout[1]),
+    Tab::Charts => charts_ui(frame, app, tabs_layout[1]),
+    Tab::Installments => installments_ui(frame, app, tabs_layout[1]),
+    Tab::Recurring => recurring_ui(frame, app, tabs_layout[1]),
   };
+
+  if let Some(form) = &app.transaction_form {
+    let title = match form.transaction_id {
+      Some(_) => "Edit transaction",
+      None => "New transaction",
+    };
+    transaction_form_ui(frame, form, title);
+  }
+
+  if let Some(form) = &app.category_form {
+    category_form_ui(frame, form);
+  }
+
+  if let Some(form) = &app.budget_form {
+    budget_form_ui(frame, form, &app.budgets.store.data.currency);
+  }
+
+  if let Some(import) = &mut app.import {
+    import_ui(frame, import);
+  }
+
+  if let Some(export) = &app.export {
+    export_ui(frame, export);
+  }
+
+  if let Some(form) = &app.recurring_form {
+    recurring_form_ui(frame, form);
+  }
+
+  if let Some(form) = &app.filter_form {
+    filter_form_ui(frame, form);
+  }
+
+  if let Some(prompt) = &app.recurring.prompt {
+    recurring_prompt_ui(frame, prompt, &app.language);
+  }
+
+  if let Some(overview) = &mut app.year_overview {
+    year_overview_ui(frame, overview, &app.converter, &app.language);
+  }
+
+  match &app.delete_confirmation {
+    Some(DeleteTarget::Transaction(transaction)) => confirm_ui(
+      frame,
+      "Delete transaction",
+      format!(
+        "Delete {:?} of {}{}?",
+        transaction.r#type,
+        transaction.money().format(&app.language),
+        match &transaction.note {
+          Some(note) => format!(" ({})", note),
+          None => String::new(),
+        }
+      ),
+    ),
+    Some(DeleteTarget::Category(category)) => confirm_ui(
+      frame,
+      "Delete category",
+      format!("Delete category {}?", category.name),
+    ),
+    Some(DeleteTarget::Recurring(template)) => confirm_ui(
+      frame,
+      "Delete recurring transaction",
+      format!(
+        "Delete {:?} recurring {:?} of {}?",
+        template.frequency,
+        template.r#type,
+        Money::new(template.amount, template.currency.clone()).format(&app.language)
+      ),
+    ),
+    None => (),
+  }
 }
@@ -6,15 +6,19 @@ use tui::{
 };
 
 use crate::{
-  enums::{selected_block::SelectedBlock, currency::CurrencyEnum},
+  enums::{language::Language, selected_block::SelectedBlock},
   models::transaction::TransactionModel,
 };
 
-use super::{blocks::create_block, colors::FOREGROUND};
+use super::{
+  blocks::create_block,
+  colors::{CYAN, FOREGROUND},
+};
 
 pub fn get_paragraph_to_details_transaction_details<'a>(
   selected_block: &'a SelectedBlock,
   transaction: &'a TransactionModel,
+  language: &Language,
   color: Color,
 ) -> Paragraph<'a> {
   let details = create_block(
@@ -37,19 +41,16 @@ pub fn get_paragraph_to_details_transaction_details<'a>(
     )),
     Spans::from(""),
     Spans::from(Span::styled(
-      format!(
-        "Amount: {} {:?}",
-        &transaction.amount, &transaction.currency
-      ),
+      format!("Amount: {}", transaction.money().format(language)),
       Style::default().fg(FOREGROUND),
     )),
-    match &transaction.currency {
-      CurrencyEnum::USD | CurrencyEnum::EUR => Spans::from(Span::styled(
+    match transaction.currency.has_exchange_rate() {
+      true => Spans::from(Span::styled(
         format!(
           "Exchange Rate: {}\n",
           match &transaction.exchange_rate {
-            Some(rate) => rate,
-            None => &0_f64,
+            Some(rate) => rate.normalize().to_string(),
+            None => String::from("-"),
           }
         ),
         Style::default().fg(FOREGROUND),
@@ -81,27 +82,30 @@ pub fn get_paragraph_to_details_transaction_details<'a>(
     )),
   ];
 
-  match &transaction.category {
-    Some(category) => {
-      spans.push(Spans::from(""));
-      spans.push(Spans::from(Span::styled(
-        "Category: ",
-        Style::default().fg(FOREGROUND),
-      )));
-      spans.push(Spans::from(""));
-      spans.push(Spans::from(Span::styled(
-        format!("Id: {}", category.category_id),
-        Style::default().fg(FOREGROUND),
-      )));
-      spans.push(Spans::from(""));
-      spans.push(Spans::from(Span::styled(
-        format!("Name: {}", category.name),
-        Style::default().fg(FOREGROUND),
-      )));
-    }
-    None => (),
+  if let Some(category) = &transaction.category {
+    spans.push(Spans::from(""));
+    spans.push(Spans::from(Span::styled(
+      "Category: ",
+      Style::default().fg(FOREGROUND),
+    )));
+    spans.push(Spans::from(""));
+    spans.push(Spans::from(Span::styled(
+      format!("Id: {}", category.category_id),
+      Style::default().fg(FOREGROUND),
+    )));
+    spans.push(Spans::from(""));
+    spans.push(Spans::from(Span::styled(
+      format!("Name: {}", category.name),
+      Style::default().fg(FOREGROUND),
+    )));
   }
 
+  spans.push(Spans::from(""));
+  spans.push(Spans::from(Span::styled(
+    "e: edit | d: delete",
+    Style::default().fg(CYAN),
+  )));
+
   Paragraph::new(spans)
     .block(details)
     .alignment(Alignment::Left)
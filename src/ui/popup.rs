@@ -0,0 +1,30 @@
+use tui::layout::{Layout, Direction, Constraint, Rect};
+
+pub fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
+  let height = height.min(area.height);
+  let vertical_margin = (area.height - height) / 2;
+
+  let popup_layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(
+      [
+        Constraint::Length(vertical_margin),
+        Constraint::Length(height),
+        Constraint::Min(0),
+      ]
+      .as_ref(),
+    )
+    .split(area);
+
+  Layout::default()
+    .direction(Direction::Horizontal)
+    .constraints(
+      [
+        Constraint::Percentage((100 - percent_x) / 2),
+        Constraint::Percentage(percent_x),
+        Constraint::Percentage((100 - percent_x) / 2),
+      ]
+      .as_ref(),
+    )
+    .split(popup_layout[1])[1]
+}
@@ -0,0 +1,156 @@
+use chrono::Local;
+use tui::{
+  backend::Backend,
+  layout::{Alignment, Constraint, Direction, Layout, Rect},
+  style::Style,
+  text::{Span, Spans},
+  widgets::{Block, BorderType, Borders, Cell, Clear, Paragraph, Row, Table},
+  Frame,
+};
+
+use crate::{
+  enums::{language::Language, selected_block::SelectedBlock},
+  models::category::CategoryModel,
+  states::{recurring::RecurringPrompt, App},
+  types::money::Money,
+};
+
+use super::{blocks::create_block, colors::*, popup::centered_rect};
+
+const WIDTHS: [Constraint; 7] = [
+  Constraint::Percentage(12),
+  Constraint::Percentage(16),
+  Constraint::Percentage(10),
+  Constraint::Percentage(12),
+  Constraint::Percentage(12),
+  Constraint::Percentage(14),
+  Constraint::Percentage(24),
+];
+
+fn category_name(categories: &[CategoryModel], category_id: Option<&String>) -> String {
+  category_id
+    .and_then(|id| {
+      categories
+        .iter()
+        .find(|category| &category.category_id == id)
+    })
+    .map(|category| category.name.clone())
+    .unwrap_or_default()
+}
+
+pub fn recurring_ui<B: Backend>(frame: &mut Frame<B>, app: &mut App, area: Rect) {
+  let today = Local::now().date_naive();
+
+  let rows: Vec<Row> = app
+    .recurring
+    .templates()
+    .iter()
+    .map(|template| {
+      Row::new(vec![
+        Cell::from(format!("{:?}", template.r#type)),
+        Cell::from(Money::new(template.amount, template.currency.clone()).format(&app.language)),
+        Cell::from(format!("{:?}", template.frequency)),
+        Cell::from(match template.next_occurrence(today) {
+          Some(date) => date.to_string(),
+          None => String::from("-"),
+        }),
+        Cell::from(match template.end {
+          Some(end) => end.to_string(),
+          None => String::from("never"),
+        }),
+        Cell::from(category_name(
+          &app.categories,
+          template.category_id.as_ref(),
+        )),
+        Cell::from(template.note.clone().unwrap_or_default()),
+      ])
+    })
+    .collect();
+
+  let table = Table::new(rows)
+    .header(
+      Row::new([
+        "Type", "Amount", "Every", "Next", "Ends", "Category", "Note",
+      ])
+      .style(Style::default().fg(BACKGROUND).bg(BLUE))
+      .bottom_margin(1),
+    )
+    .block(create_block(
+      String::from("Recurring"),
+      &app.selected_block,
+      SelectedBlock::Recurring,
+      BLUE,
+    ))
+    .style(Style::default().fg(FOREGROUND))
+    .widths(&WIDTHS)
+    .highlight_style(Style::default().bg(GREY).fg(YELLOW));
+
+  frame.render_stateful_widget(table, area, &mut app.recurring.table);
+}
+
+pub fn recurring_prompt_ui<B: Backend>(
+  frame: &mut Frame<B>,
+  prompt: &RecurringPrompt,
+  language: &Language,
+) {
+  let height = prompt.occurrences.len() as u16 + 6;
+  let area = centered_rect(50, height, frame.size());
+
+  let block = Block::default()
+    .title(format!("Recurring transactions for {}", prompt.period))
+    .borders(Borders::ALL)
+    .border_type(BorderType::Thick)
+    .style(Style::default().bg(BACKGROUND).fg(BLUE));
+
+  let layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(
+      [
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+      ]
+      .as_ref(),
+    )
+    .margin(1)
+    .split(area);
+
+  let lines: Vec<Spans> = prompt
+    .occurrences
+    .iter()
+    .map(|occurrence| {
+      let request = &occurrence.request;
+
+      Spans::from(format!(
+        "{}  {:?}  {}  {}",
+        occurrence.date,
+        request.r#type,
+        Money::new(request.amount, request.currency.clone()).format(language),
+        request.note.clone().unwrap_or_default()
+      ))
+    })
+    .collect();
+
+  let message = Paragraph::new(Spans::from(Span::styled(
+    format!(
+      "Create {} pending transaction(s)?",
+      prompt.occurrences.len()
+    ),
+    Style::default().fg(FOREGROUND),
+  )));
+
+  let hints = Paragraph::new(Spans::from(Span::styled(
+    "y/Enter: create | s: skip | n/Esc: later",
+    Style::default().fg(CYAN),
+  )))
+  .alignment(Alignment::Center);
+
+  frame.render_widget(Clear, area);
+  frame.render_widget(block, area);
+  frame.render_widget(message, layout[0]);
+  frame.render_widget(
+    Paragraph::new(lines).style(Style::default().fg(FOREGROUND)),
+    layout[1],
+  );
+  frame.render_widget(hints, layout[2]);
+}
@@ -0,0 +1,184 @@
+use tui::{
+  backend::Backend,
+  layout::{Layout, Direction, Constraint, Alignment},
+  style::Style,
+  text::{Spans, Span},
+  widgets::{Block, Borders, Clear, Paragraph, Wrap},
+  Frame,
+};
+
+use crate::{
+  enums::recurring_form_field::RecurringFormField, states::recurring_form::RecurringFormState,
+};
+
+use super::{
+  colors::*,
+  inputs::{create_input, create_select, set_input_cursor},
+  popup::centered_rect,
+};
+
+pub fn recurring_form_ui<B: Backend>(frame: &mut Frame<B>, form: &RecurringFormState) {
+  let area = centered_rect(60, 18, frame.size());
+
+  let title = match form.template_id {
+    Some(_) => "Edit recurring transaction",
+    None => "New recurring transaction",
+  };
+
+  let block = Block::default()
+    .title(title)
+    .borders(Borders::ALL)
+    .style(Style::default().bg(BACKGROUND).fg(BLUE));
+
+  let inner = block.inner(area);
+
+  frame.render_widget(Clear, area);
+  frame.render_widget(block, area);
+
+  let layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(
+      [
+        Constraint::Length(12),
+        Constraint::Length(2),
+        Constraint::Length(1),
+      ]
+      .as_ref(),
+    )
+    .split(inner);
+
+  let columns = Layout::default()
+    .direction(Direction::Horizontal)
+    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+    .split(layout[0]);
+
+  let field_constraints = [
+    Constraint::Length(3),
+    Constraint::Length(3),
+    Constraint::Length(3),
+    Constraint::Length(3),
+  ];
+
+  let left = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(field_constraints.as_ref())
+    .split(columns[0]);
+
+  let right = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(field_constraints.as_ref())
+    .split(columns[1]);
+
+  let is_selected = |field: RecurringFormField| form.selected_field == field;
+
+  frame.render_widget(
+    create_select(
+      "Type",
+      format!("{:?}", form.transaction_type),
+      is_selected(RecurringFormField::Type),
+    ),
+    left[0],
+  );
+
+  frame.render_widget(
+    create_input(
+      "Amount",
+      &form.amount,
+      is_selected(RecurringFormField::Amount),
+      false,
+      left[1].width,
+    ),
+    left[1],
+  );
+
+  frame.render_widget(
+    create_select(
+      "Currency",
+      form.currency.to_string(),
+      is_selected(RecurringFormField::Currency),
+    ),
+    left[2],
+  );
+
+  frame.render_widget(
+    create_select(
+      "Category",
+      match form.selected_category() {
+        Some(category) => category.name.clone(),
+        None => String::from("None"),
+      },
+      is_selected(RecurringFormField::Category),
+    ),
+    left[3],
+  );
+
+  frame.render_widget(
+    create_select(
+      "Every",
+      format!("{:?}", form.frequency),
+      is_selected(RecurringFormField::Frequency),
+    ),
+    right[0],
+  );
+
+  frame.render_widget(
+    create_input(
+      "Start (YYYY-MM-DD)",
+      &form.start,
+      is_selected(RecurringFormField::Start),
+      false,
+      right[1].width,
+    ),
+    right[1],
+  );
+
+  frame.render_widget(
+    create_input(
+      "End (optional)",
+      &form.end,
+      is_selected(RecurringFormField::End),
+      false,
+      right[2].width,
+    ),
+    right[2],
+  );
+
+  frame.render_widget(
+    create_input(
+      "Note",
+      &form.note,
+      is_selected(RecurringFormField::Note),
+      false,
+      right[3].width,
+    ),
+    right[3],
+  );
+
+  if let Some(error) = &form.error {
+    let error_paragraph = Paragraph::new(Spans::from(Span::styled(
+      error.clone(),
+      Style::default().fg(RED),
+    )))
+    .wrap(Wrap { trim: true });
+
+    frame.render_widget(error_paragraph, layout[1]);
+  }
+
+  let help = Paragraph::new(Spans::from(Span::styled(
+    "Tab: next field | Left/Right: change option | Enter: save | Esc: cancel",
+    Style::default().fg(CYAN),
+  )))
+  .alignment(Alignment::Center);
+  frame.render_widget(help, layout[2]);
+
+  if let Some(input) = form.selected_input() {
+    let input_area = match form.selected_field {
+      RecurringFormField::Amount => left[1],
+      RecurringFormField::Start => right[1],
+      RecurringFormField::End => right[2],
+      _ => right[3],
+    };
+
+    set_input_cursor(frame, input, input_area);
+  }
+}
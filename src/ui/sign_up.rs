@@ -0,0 +1,97 @@
+use tui::{
+  backend::Backend,
+  layout::{Layout, Direction, Constraint, Alignment},
+  style::Style,
+  text::{Spans, Span},
+  widgets::{Block, Borders, Clear, Paragraph, Wrap},
+  Frame,
+};
+
+use crate::{enums::sign_up_field::SignUpField, states::sign_up::SignUpState};
+
+use super::{
+  colors::*,
+  inputs::{create_input, set_input_cursor},
+  popup::centered_rect,
+};
+
+pub fn sign_up_ui<B: Backend>(frame: &mut Frame<B>, state: &SignUpState) {
+  let background = Block::default().style(Style::default().bg(BACKGROUND));
+  frame.render_widget(background, frame.size());
+
+  let area = centered_rect(50, 23, frame.size());
+
+  let block = Block::default()
+    .title("Sign up")
+    .borders(Borders::ALL)
+    .style(Style::default().bg(BACKGROUND).fg(ORANGE));
+
+  let inner = block.inner(area);
+
+  frame.render_widget(Clear, area);
+  frame.render_widget(block, area);
+
+  let layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(
+      [
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(1),
+      ]
+      .as_ref(),
+    )
+    .split(inner);
+
+  let fields = [
+    ("Email", &state.email, SignUpField::Email, false),
+    (
+      "First name",
+      &state.first_name,
+      SignUpField::FirstName,
+      false,
+    ),
+    ("Last name", &state.last_name, SignUpField::LastName, false),
+    ("Password", &state.password, SignUpField::Password, true),
+    (
+      "Confirm password",
+      &state.confirm_password,
+      SignUpField::ConfirmPassword,
+      true,
+    ),
+  ];
+
+  for (index, (title, input, field, is_secret)) in fields.into_iter().enumerate() {
+    let is_selected = state.selected_field == field;
+
+    frame.render_widget(
+      create_input(title, input, is_selected, is_secret, layout[index].width),
+      layout[index],
+    );
+
+    if is_selected {
+      set_input_cursor(frame, input, layout[index]);
+    }
+  }
+
+  if let Some(error) = &state.error {
+    let error_paragraph = Paragraph::new(Spans::from(Span::styled(
+      error.clone(),
+      Style::default().fg(RED),
+    )))
+    .wrap(Wrap { trim: true });
+
+    frame.render_widget(error_paragraph, layout[5]);
+  }
+
+  let help = Paragraph::new(Spans::from(Span::styled(
+    "Tab: next field | Enter: create account | Esc: back to login",
+    Style::default().fg(CYAN),
+  )))
+  .alignment(Alignment::Center);
+  frame.render_widget(help, layout[6]);
+}
@@ -0,0 +1,115 @@
+use tui::{
+  backend::Backend,
+  layout::{Alignment, Constraint, Direction, Layout, Rect},
+  style::{Modifier, Style},
+  text::{Span, Spans},
+  widgets::Paragraph,
+  Frame,
+};
+
+use crate::{enums::notification_level::NotificationLevel, states::App};
+
+use super::colors::*;
+
+const LAST_REFRESH_WIDTH: u16 = 24;
+
+pub fn status_bar_ui<B: Backend>(frame: &mut Frame<B>, app: &App, area: Rect) {
+  let chunks = Layout::default()
+    .direction(Direction::Horizontal)
+    .constraints([Constraint::Min(0), Constraint::Length(LAST_REFRESH_WIDTH)].as_ref())
+    .split(area);
+
+  if let Some(input) = &app.search {
+    let search = Paragraph::new(Spans::from(vec![
+      Span::styled("/", Style::default().fg(YELLOW)),
+      Span::styled(input.value(), Style::default().fg(FOREGROUND)),
+    ]));
+
+    frame.render_widget(search, chunks[0]);
+    frame.set_cursor(chunks[0].x + 1 + input.visual_cursor() as u16, chunks[0].y);
+    return;
+  }
+
+  let mut spans: Vec<Span> = Vec::new();
+
+  if app.offline {
+    spans.push(Span::styled(
+      " OFFLINE ",
+      Style::default()
+        .fg(BACKGROUND)
+        .bg(RED)
+        .add_modifier(Modifier::BOLD),
+    ));
+
+    let pending = app.cache.data.pending.len();
+    if pending > 0 {
+      spans.push(Span::styled(
+        format!(" {} pending", pending),
+        Style::default().fg(YELLOW),
+      ));
+    }
+
+    spans.push(Span::raw(" "));
+  }
+
+  if !app.filter.is_empty() {
+    spans.push(Span::styled(
+      " FILTER ",
+      Style::default()
+        .fg(BACKGROUND)
+        .bg(YELLOW)
+        .add_modifier(Modifier::BOLD),
+    ));
+    spans.push(Span::styled(
+      format!(" {} ", app.filter.describe()),
+      Style::default().fg(YELLOW),
+    ));
+  }
+
+  let status = if app.await_data {
+    vec![
+      Span::styled(app.notifications.spinner(), Style::default().fg(YELLOW)),
+      Span::styled(" Loading...", Style::default().fg(FOREGROUND)),
+    ]
+  } else {
+    match app.notifications.current() {
+      Some(notification) => {
+        let (label, color) = match notification.level {
+          NotificationLevel::Info => ("INFO", CYAN),
+          NotificationLevel::Warn => ("WARN", YELLOW),
+          NotificationLevel::Error => ("ERROR", RED),
+        };
+
+        vec![
+          Span::styled(
+            format!(" {} ", label),
+            Style::default()
+              .fg(BACKGROUND)
+              .bg(color)
+              .add_modifier(Modifier::BOLD),
+          ),
+          Span::styled(
+            format!(" {}", notification.message),
+            Style::default().fg(color),
+          ),
+        ]
+      }
+      None => Vec::new(),
+    }
+  };
+
+  spans.extend(status);
+
+  let (last_refresh, color) = match app.notifications.last_refresh {
+    Some(date) if app.offline => (format!("Stale {}", date.format("%d/%m %H:%M")), YELLOW),
+    Some(date) => (format!("Last refresh {}", date.format("%H:%M:%S")), GREEN),
+    None => (String::from("Not refreshed yet"), GREEN),
+  };
+
+  frame.render_widget(Paragraph::new(Spans::from(spans)), chunks[0]);
+  frame.render_widget(
+    Paragraph::new(Span::styled(last_refresh, Style::default().fg(color)))
+      .alignment(Alignment::Right),
+    chunks[1],
+  );
+}
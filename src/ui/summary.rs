@@ -8,7 +8,46 @@ use tui::{
 };
 
 use super::colors::{BACKGROUND, YELLOW};
-use crate::states::App;
+use crate::{
+  enums::{currency::CurrencyEnum, language::Language},
+  states::App,
+  types::{money::Money, responses::transaction::TransactionTotal},
+  utils::currency::Conversion,
+};
+
+/// The total line followed by one line per currency present in the month.
+fn summary_spans<'a>(
+  total: String,
+  summary: &TransactionTotal,
+  language: &Language,
+) -> Vec<Spans<'a>> {
+  let mut spans = vec![Spans::from(total)];
+
+  spans.extend(summary.currencies.iter().map(|(currency, amount)| {
+    Spans::from(format!(
+      "{}: {}",
+      currency,
+      Money::new(*amount, currency.clone()).format(language)
+    ))
+  }));
+
+  spans
+}
+
+/// Amounts without a known rate are left out of the total and flagged.
+fn total_line(
+  label: &str,
+  total: &Conversion,
+  currency: &CurrencyEnum,
+  language: &Language,
+) -> String {
+  let amount = Money::new(total.amount, currency.clone()).format(language);
+
+  match total.missing {
+    0 => format!("{}: {}", label, amount),
+    missing => format!("{}: {} ({} without rate)", label, amount, missing),
+  }
+}
 
 pub fn create_summeries<B: Backend>(app: &mut App, frame: &mut Frame<B>, layout: Vec<Rect>) {
   let incomes = Block::default()
@@ -27,39 +66,52 @@ pub fn create_summeries<B: Backend>(app: &mut App, frame: &mut Frame<B>, layout:
     .style(Style::default().bg(BACKGROUND).fg(YELLOW));
 
   let totals = Block::default()
-    .title("Total")
+    .title(format!("Total {}", app.tree.current))
     .borders(Borders::ALL)
     .style(Style::default().bg(BACKGROUND).fg(YELLOW));
 
-  let incomes_summery = &app.summary.incomes;
-
-  let incomes_span = vec![
-    Spans::from(format!("Total: ${} UYU", incomes_summery.total)),
-    Spans::from(format!("UYU: ${}", incomes_summery.uyu)),
-    Spans::from(format!("USD: ${}", incomes_summery.usd)),
-    Spans::from(format!("EUR: ${}", incomes_summery.eur)),
-  ];
-
-  let expenses_span = vec![
-    Spans::from(format!("Total: ${} UYU", &app.summary.expenses.total)),
-    Spans::from(format!("UYU: ${}", &app.summary.expenses.uyu)),
-    Spans::from(format!("USD: ${}", &app.summary.expenses.usd)),
-    Spans::from(format!("EUR: ${}", &app.summary.expenses.eur)),
-  ];
-
-  let savings_span = vec![
-    Spans::from(format!("Total: ${} UYU", &app.summary.savings.total)),
-    Spans::from(format!("UYU: ${}", &app.summary.savings.uyu)),
-    Spans::from(format!("USD: ${}", &app.summary.savings.usd)),
-    Spans::from(format!("EUR: ${}", &app.summary.savings.eur)),
-  ];
+  let converter = &app.converter;
+  let display = &converter.display;
+  let language = &app.language;
+
+  let incomes_total = converter.total(&app.table_state.row_incomes);
+  let expenses_total = converter.total(&app.table_state.row_expenses);
+  let savings_total = converter.total(&app.table_state.row_savings);
+
+  let incomes_span = summary_spans(
+    total_line("Total", &incomes_total, display, language),
+    &app.summary.incomes,
+    language,
+  );
+  let expenses_span = summary_spans(
+    total_line("Total", &expenses_total, display, language),
+    &app.summary.expenses,
+    language,
+  );
+  let savings_span = summary_spans(
+    total_line("Total", &savings_total, display, language),
+    &app.summary.savings,
+    language,
+  );
+
+  let current_money = Conversion {
+    amount: incomes_total.amount - expenses_total.amount - savings_total.amount,
+    missing: incomes_total.missing + expenses_total.missing + savings_total.missing,
+  };
 
   let current_money_span = vec![
-    Spans::from(format!(
-      "Current Money: ${} UYU",
-      (app.summary.incomes.total - app.summary.expenses.total - app.summary.savings.total).round()
+    Spans::from(total_line(
+      "Current Money",
+      &current_money,
+      display,
+      language,
+    )),
+    Spans::from(total_line(
+      "Total Savings",
+      &converter.convert_total(&app.total_saving),
+      display,
+      language,
     )),
-    Spans::from(format!("Total Savings: ${} USD", &app.total_saving)),
   ];
 
   let incomes_paragraph = Paragraph::new(incomes_span)
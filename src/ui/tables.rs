@@ -1,141 +1,243 @@
-use crate::{states::App, enums::selected_block::SelectedBlock};
+use crate::{
+  states::{tables::CustomTableState, App},
+  enums::selected_block::SelectedBlock,
+  utils::wrap_text,
+};
 use tui::{
   widgets::{Table, Row, Cell},
-  style::Style,
+  style::{Style, Color},
   layout::Constraint,
-  text::Span,
+  text::{Span, Spans, Text},
 };
 
 use super::colors::*;
 use super::blocks::create_block;
 
-pub fn create_incomes_table<'a>(app: &mut App<'a>) -> Table<'a> {
+const DAY_WIDTH: u16 = 4;
+const AMOUNT_WIDTH: u16 = 15;
+const MIN_CATEGORY_WIDTH: u16 = 8;
+const MAX_CATEGORY_WIDTH: u16 = 20;
+
+pub fn transactions_widths(width: u16) -> Vec<Constraint> {
+  // Borders take two columns and the table leaves one space between each column.
+  let available = width.saturating_sub(2 + 3 + DAY_WIDTH + AMOUNT_WIDTH);
+  let category = (available / 3).clamp(MIN_CATEGORY_WIDTH, MAX_CATEGORY_WIDTH);
+  let note = available.saturating_sub(category);
+
+  vec![
+    Constraint::Length(DAY_WIDTH),
+    Constraint::Length(AMOUNT_WIDTH),
+    Constraint::Length(note),
+    Constraint::Length(category),
+  ]
+}
+
+/// Splits `line` into spans with the case-insensitive matches of `needle` highlighted.
+fn highlight_line(line: &str, needle: &str) -> Spans<'static> {
+  let lowercase = line.to_lowercase();
+
+  // Byte offsets of the lowercase copy only line up when lowercasing keeps the length.
+  if needle.is_empty() || lowercase.len() != line.len() {
+    return Spans::from(line.to_string());
+  }
+
+  let mut spans = Vec::new();
+  let mut start = 0;
+
+  for (index, _) in lowercase.match_indices(needle) {
+    let end = index + needle.len();
+
+    if !line.is_char_boundary(index) || !line.is_char_boundary(end) {
+      continue;
+    }
+
+    if index > start {
+      spans.push(Span::raw(line[start..index].to_string()));
+    }
+
+    spans.push(Span::styled(
+      line[index..end].to_string(),
+      Style::default().fg(BACKGROUND).bg(YELLOW),
+    ));
+    start = end;
+  }
+
+  if start < line.len() {
+    spans.push(Span::raw(line[start..].to_string()));
+  }
+
+  Spans::from(spans)
+}
+
+fn create_rows<'a>(table: &CustomTableState, widths: &[Constraint], needle: &str) -> Vec<Row<'a>> {
+  let needle = needle.trim().to_lowercase();
+
+  table
+    .items
+    .iter()
+    .map(|item| {
+      let cells: Vec<String> = item
+        .iter()
+        .enumerate()
+        .map(|(index, content)| match widths.get(index) {
+          Some(Constraint::Length(width)) => wrap_text(content, *width as usize),
+          _ => content.clone(),
+        })
+        .collect();
+
+      let height = cells
+        .iter()
+        .map(|content| content.chars().filter(|c| *c == '\n').count())
+        .max()
+        .unwrap_or(0)
+        + 1;
+
+      let cells = cells.into_iter().map(|c| {
+        Cell::from(Text::from(
+          c.lines()
+            .map(|line| highlight_line(line, &needle))
+            .collect::<Vec<Spans>>(),
+        ))
+      });
+      Row::new(cells).height(height as u16).bottom_margin(1)
+    })
+    .collect()
+}
+
+fn create_transactions_table<'a>(
+  app: &App,
+  table: &CustomTableState,
+  total: usize,
+  title: &str,
+  block: SelectedBlock,
+  color: Color,
+  widths: &'a [Constraint],
+) -> Table<'a> {
   let selected_style = Style::default().bg(GREY).fg(YELLOW);
 
   let header_cells = app.transactions_header.iter().map(|h| Cell::from(*h));
 
   let header = Row::new(header_cells)
-    .style(Style::default().fg(BACKGROUND).bg(GREEN))
+    .style(Style::default().fg(BACKGROUND).bg(color))
     .height(1)
     .bottom_margin(1);
 
-  let rows = app.table_state.incomes.items.iter().map(|item| {
-    let height = item
-      .iter()
-      .map(|content| content.chars().filter(|c| *c == '\n').count())
-      .max()
-      .unwrap_or(0)
-      + 1;
-
-    let cells = item.iter().map(|c| Cell::from(Span::from(c.clone())));
-    Row::new(cells).height(height as u16).bottom_margin(1)
-  });
+  let title = if app.filter.is_empty() {
+    String::from(title)
+  } else {
+    format!("{} ({}/{})", title, table.items.len(), total)
+  };
 
-  let block = create_block(
-    String::from("Incomes"),
-    &app.selected_block,
-    SelectedBlock::Incomes,
-    GREEN,
-  );
+  let block = create_block(title, &app.selected_block, block, color);
 
-  Table::new(rows)
+  Table::new(create_rows(table, widths, &app.filter.text))
     .header(header)
     .block(block)
     .highlight_style(selected_style)
     .highlight_symbol("")
-    .widths(
-      [
-        Constraint::Length(4),
-        Constraint::Length(15),
-        Constraint::Length(30),
-      ]
-      .as_ref(),
-    )
+    .widths(widths)
+}
+
+pub fn create_incomes_table<'a>(app: &App, widths: &'a [Constraint]) -> Table<'a> {
+  create_transactions_table(
+    app,
+    &app.table_state.incomes,
+    app.table_state.row_incomes.len(),
+    "Incomes",
+    SelectedBlock::Incomes,
+    GREEN,
+    widths,
+  )
 }
 
-pub fn create_expenses_table<'a>(app: &mut App<'a>) -> Table<'a> {
+pub fn create_expenses_table<'a>(app: &App, widths: &'a [Constraint]) -> Table<'a> {
+  create_transactions_table(
+    app,
+    &app.table_state.expenses,
+    app.table_state.row_expenses.len(),
+    "Expenses",
+    SelectedBlock::Expenses,
+    RED,
+    widths,
+  )
+}
+
+pub fn create_savings_table<'a>(app: &App, widths: &'a [Constraint]) -> Table<'a> {
+  create_transactions_table(
+    app,
+    &app.table_state.savings,
+    app.table_state.row_savings.len(),
+    "Savings",
+    SelectedBlock::Savings,
+    FOREGROUND,
+    widths,
+  )
+}
+
+const IMPORT_INCLUDED_WIDTH: u16 = 3;
+const IMPORT_DATE_WIDTH: u16 = 10;
+const IMPORT_STATUS_WIDTH: u16 = 9;
+
+pub fn import_widths(width: u16) -> Vec<Constraint> {
+  let available = width.saturating_sub(
+    2 + 4 + IMPORT_INCLUDED_WIDTH + IMPORT_DATE_WIDTH + AMOUNT_WIDTH + IMPORT_STATUS_WIDTH,
+  );
+
+  vec![
+    Constraint::Length(IMPORT_INCLUDED_WIDTH),
+    Constraint::Length(IMPORT_DATE_WIDTH),
+    Constraint::Length(AMOUNT_WIDTH),
+    Constraint::Length(IMPORT_STATUS_WIDTH),
+    Constraint::Length(available),
+  ]
+}
+
+pub fn create_import_table<'a>(table: &CustomTableState, widths: &'a [Constraint]) -> Table<'a> {
   let selected_style = Style::default().bg(GREY).fg(YELLOW);
 
-  let header_cells = app.transactions_header.iter().map(|h| Cell::from(*h));
+  let header_cells = ["", "Date", "Amount", "Status", "Note"]
+    .into_iter()
+    .map(Cell::from);
 
   let header = Row::new(header_cells)
-    .style(Style::default().fg(BACKGROUND).bg(RED))
+    .style(Style::default().fg(BACKGROUND).bg(ORANGE))
     .height(1)
     .bottom_margin(1);
 
-  let rows = app.table_state.expenses.items.iter().map(|item| {
-    let height = item
-      .iter()
-      .map(|content| content.chars().filter(|c| *c == '\n').count())
-      .max()
-      .unwrap_or(0)
-      + 1;
-
-    let cells = item.iter().map(|c| Cell::from(Span::from(c.clone())));
-    Row::new(cells).height(height as u16).bottom_margin(1)
-  });
-
-  let block = create_block(
-    String::from("Expenses"),
-    &app.selected_block,
-    SelectedBlock::Expenses,
-    RED,
-  );
-
-  Table::new(rows)
+  Table::new(create_rows(table, widths, ""))
     .header(header)
-    .block(block)
     .highlight_style(selected_style)
     .highlight_symbol("")
-    .widths(
-      [
-        Constraint::Length(4),
-        Constraint::Length(15),
-        Constraint::Length(30),
-      ]
-      .as_ref(),
-    )
+    .widths(widths)
 }
 
-pub fn create_savings_table<'a>(app: &mut App<'a>) -> Table<'a> {
+pub fn create_categories_table<'a>(app: &App) -> Table<'a> {
   let selected_style = Style::default().bg(GREY).fg(YELLOW);
 
-  let header_cells = app.transactions_header.iter().map(|h| Cell::from(*h));
+  let header_cells = ["Name", "Type", "Note"].into_iter().map(Cell::from);
 
   let header = Row::new(header_cells)
-    .style(Style::default().fg(BACKGROUND).bg(FOREGROUND))
+    .style(Style::default().fg(BACKGROUND).bg(CYAN))
     .height(1)
     .bottom_margin(1);
 
-  let rows = app.table_state.savings.items.iter().map(|item| {
-    let height = item
-      .iter()
-      .map(|content| content.chars().filter(|c| *c == '\n').count())
-      .max()
-      .unwrap_or(0)
-      + 1;
-
-    let cells = item.iter().map(|c| Cell::from(Span::from(c.clone())));
-    Row::new(cells).height(height as u16).bottom_margin(1)
-  });
-
   let block = create_block(
-    String::from("Savings"),
+    String::from("Categories"),
     &app.selected_block,
-    SelectedBlock::Savings,
-    FOREGROUND,
+    SelectedBlock::Categories,
+    CYAN,
   );
 
-  Table::new(rows)
+  Table::new(create_rows(&app.categories_table, &[], ""))
     .header(header)
     .block(block)
     .highlight_style(selected_style)
     .highlight_symbol("")
     .widths(
       [
-        Constraint::Length(4),
-        Constraint::Length(15),
-        Constraint::Length(30),
+        Constraint::Percentage(30),
+        Constraint::Length(14),
+        Constraint::Percentage(56),
       ]
       .as_ref(),
     )
@@ -0,0 +1,199 @@
+use tui::{
+  backend::Backend,
+  layout::{Layout, Direction, Constraint, Alignment},
+  style::Style,
+  text::{Spans, Span},
+  widgets::{Block, Borders, Clear, Paragraph, Wrap},
+  Frame,
+};
+
+use crate::{
+  enums::transaction_form_field::TransactionFormField,
+  states::transaction_form::TransactionFormState,
+};
+
+use super::{
+  colors::*,
+  inputs::{create_input, create_select, set_input_cursor},
+  popup::centered_rect,
+};
+
+pub fn transaction_form_ui<B: Backend>(
+  frame: &mut Frame<B>,
+  form: &TransactionFormState,
+  title: &str,
+) {
+  let area = centered_rect(60, 21, frame.size());
+
+  let block = Block::default()
+    .title(title.to_string())
+    .borders(Borders::ALL)
+    .style(Style::default().bg(BACKGROUND).fg(ORANGE));
+
+  let inner = block.inner(area);
+
+  frame.render_widget(Clear, area);
+  frame.render_widget(block, area);
+
+  let layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(
+      [
+        Constraint::Length(15),
+        Constraint::Length(2),
+        Constraint::Length(1),
+      ]
+      .as_ref(),
+    )
+    .split(inner);
+
+  let columns = Layout::default()
+    .direction(Direction::Horizontal)
+    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+    .split(layout[0]);
+
+  let field_constraints = [
+    Constraint::Length(3),
+    Constraint::Length(3),
+    Constraint::Length(3),
+    Constraint::Length(3),
+    Constraint::Length(3),
+  ];
+
+  let left = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(field_constraints.as_ref())
+    .split(columns[0]);
+
+  let right = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(field_constraints.as_ref())
+    .split(columns[1]);
+
+  let is_selected = |field: TransactionFormField| form.selected_field == field;
+
+  frame.render_widget(
+    create_select(
+      "Type",
+      format!("{:?}", form.transaction_type),
+      is_selected(TransactionFormField::Type),
+    ),
+    left[0],
+  );
+
+  frame.render_widget(
+    create_input(
+      "Amount",
+      &form.amount,
+      is_selected(TransactionFormField::Amount),
+      false,
+      left[1].width,
+    ),
+    left[1],
+  );
+
+  frame.render_widget(
+    create_select(
+      "Currency",
+      form.currency.to_string(),
+      is_selected(TransactionFormField::Currency),
+    ),
+    left[2],
+  );
+
+  if form.currency.has_exchange_rate() {
+    frame.render_widget(
+      create_input(
+        "Exchange rate",
+        &form.exchange_rate,
+        is_selected(TransactionFormField::ExchangeRate),
+        false,
+        left[3].width,
+      ),
+      left[3],
+    );
+  }
+
+  frame.render_widget(
+    create_select(
+      "Category",
+      match form.selected_category() {
+        Some(category) => category.name.clone(),
+        None => String::from("None"),
+      },
+      is_selected(TransactionFormField::Category),
+    ),
+    left[4],
+  );
+
+  frame.render_widget(
+    create_input(
+      "Day",
+      &form.day,
+      is_selected(TransactionFormField::Day),
+      false,
+      right[0].width,
+    ),
+    right[0],
+  );
+
+  frame.render_widget(
+    create_select(
+      "Month",
+      format!("{:?}", form.month),
+      is_selected(TransactionFormField::Month),
+    ),
+    right[1],
+  );
+
+  frame.render_widget(
+    create_input(
+      "Year",
+      &form.year,
+      is_selected(TransactionFormField::Year),
+      false,
+      right[2].width,
+    ),
+    right[2],
+  );
+
+  frame.render_widget(
+    create_input(
+      "Note",
+      &form.note,
+      is_selected(TransactionFormField::Note),
+      false,
+      right[3].width,
+    ),
+    right[3],
+  );
+
+  if let Some(error) = &form.error {
+    let error_paragraph = Paragraph::new(Spans::from(Span::styled(
+      error.clone(),
+      Style::default().fg(RED),
+    )))
+    .wrap(Wrap { trim: true });
+
+    frame.render_widget(error_paragraph, layout[1]);
+  }
+
+  let help = Paragraph::new(Spans::from(Span::styled(
+    "Tab: next field | Left/Right: change option | Enter: save | Esc: cancel",
+    Style::default().fg(CYAN),
+  )))
+  .alignment(Alignment::Center);
+  frame.render_widget(help, layout[2]);
+
+  if let Some(input) = form.selected_input() {
+    let input_area = match form.selected_field {
+      TransactionFormField::Amount => left[1],
+      TransactionFormField::ExchangeRate => left[3],
+      TransactionFormField::Day => right[0],
+      TransactionFormField::Year => right[2],
+      _ => right[3],
+    };
+
+    set_input_cursor(frame, input, input_area);
+  }
+}
@@ -0,0 +1,137 @@
+use rust_decimal::Decimal;
+use tui::{
+  backend::Backend,
+  layout::{Alignment, Constraint, Direction, Layout},
+  style::{Modifier, Style},
+  text::{Span, Spans},
+  widgets::{Block, BorderType, Borders, Cell, Clear, Paragraph, Row, Table},
+  Frame,
+};
+
+use crate::{
+  enums::language::Language,
+  states::year_overview::{MonthTotals, YearOverviewState},
+  utils::currency::Converter,
+};
+
+use super::{colors::*, popup::centered_rect};
+
+const WIDTHS: [Constraint; 5] = [
+  Constraint::Percentage(16),
+  Constraint::Percentage(21),
+  Constraint::Percentage(21),
+  Constraint::Percentage(21),
+  Constraint::Percentage(21),
+];
+
+fn amount_cells<'a>(
+  totals: &MonthTotals,
+  converter: &Converter,
+  language: &Language,
+) -> Vec<Cell<'a>> {
+  [
+    totals.incomes,
+    totals.expenses,
+    totals.savings,
+    totals.net(),
+  ]
+  .into_iter()
+  .map(|amount: Decimal| Cell::from(converter.convert_uyu(amount).format(language)))
+  .collect()
+}
+
+fn totals_row<'a>(
+  label: &'a str,
+  totals: &MonthTotals,
+  converter: &Converter,
+  language: &Language,
+) -> Row<'a> {
+  let mut cells = vec![Cell::from(label)];
+  cells.extend(amount_cells(totals, converter, language));
+
+  Row::new(cells).style(
+    Style::default()
+      .fg(YELLOW_LITE)
+      .add_modifier(Modifier::BOLD),
+  )
+}
+
+pub fn year_overview_ui<B: Backend>(
+  frame: &mut Frame<B>,
+  overview: &mut YearOverviewState,
+  converter: &Converter,
+  language: &Language,
+) {
+  let area = centered_rect(70, 22, frame.size());
+
+  let currency = converter.convert_uyu(Decimal::ZERO).currency;
+  let title = if overview.loading {
+    format!("Year {} ({}) loading...", overview.year, currency)
+  } else {
+    format!("Year {} ({})", overview.year, currency)
+  };
+
+  let block = Block::default()
+    .title(title)
+    .borders(Borders::ALL)
+    .border_type(BorderType::Thick)
+    .style(Style::default().bg(BACKGROUND).fg(YELLOW));
+
+  let layout = Layout::default()
+    .direction(Direction::Vertical)
+    .constraints([Constraint::Min(0), Constraint::Length(1)].as_ref())
+    .split(block.inner(area));
+
+  let best_and_worst = overview.best_and_worst();
+
+  let mut rows: Vec<Row> = overview
+    .months
+    .iter()
+    .enumerate()
+    .map(|(index, (period, totals))| {
+      let mut cells = vec![Cell::from(String::from(period.month.clone()))];
+
+      match totals {
+        Some(totals) => cells.extend(amount_cells(totals, converter, language)),
+        None => cells.extend((0..4).map(|_| Cell::from("-"))),
+      }
+
+      let color = match best_and_worst {
+        Some((best, _)) if best == index => GREEN,
+        Some((_, worst)) if worst == index => RED,
+        _ => FOREGROUND,
+      };
+
+      Row::new(cells).style(Style::default().fg(color))
+    })
+    .collect();
+
+  rows.push(Row::new(vec![Cell::from("")]));
+  rows.push(totals_row("Total", &overview.total(), converter, language));
+  rows.push(totals_row(
+    "Average",
+    &overview.average(),
+    converter,
+    language,
+  ));
+
+  let header = Row::new(["Month", "Incomes", "Expenses", "Savings", "Net"])
+    .style(Style::default().fg(BACKGROUND).bg(YELLOW))
+    .bottom_margin(1);
+
+  let table = Table::new(rows)
+    .header(header)
+    .widths(&WIDTHS)
+    .highlight_style(Style::default().bg(GREY).fg(YELLOW));
+
+  let hints = Paragraph::new(Spans::from(Span::styled(
+    "↑/↓: move | Enter: open month | Esc: close",
+    Style::default().fg(CYAN),
+  )))
+  .alignment(Alignment::Center);
+
+  frame.render_widget(Clear, area);
+  frame.render_widget(block, area);
+  frame.render_stateful_widget(table, layout[0], &mut overview.table);
+  frame.render_widget(hints, layout[1]);
+}
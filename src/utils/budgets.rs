@@ -0,0 +1,58 @@
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::currency::CurrencyEnum, models::custom_error::CustomError};
+
+use super::fs::{JsonData, JsonFile};
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetsData {
+  /// Currency every limit is expressed in.
+  #[serde(default)]
+  pub currency: CurrencyEnum,
+  pub overall: Option<Decimal>,
+  /// Monthly limits keyed by category id.
+  #[serde(default)]
+  pub categories: BTreeMap<String, Decimal>,
+}
+
+impl JsonData for BudgetsData {
+  const FILE_NAME: &'static str = "budgets.json";
+}
+
+/// Monthly spending limits, kept in a file next to the config.
+pub type Budgets = JsonFile<BudgetsData>;
+
+impl Budgets {
+  /// The overall limit when `category_id` is `None`.
+  pub fn limit(&self, category_id: Option<&str>) -> Option<Decimal> {
+    match category_id {
+      Some(category_id) => self.data.categories.get(category_id).copied(),
+      None => self.data.overall,
+    }
+  }
+
+  /// Sets or clears a limit, only keeping the change once it was written to disk.
+  pub fn set_limit(
+    &mut self,
+    category_id: Option<&str>,
+    limit: Option<Decimal>,
+  ) -> Result<(), CustomError> {
+    let mut data = self.data.clone();
+
+    match (category_id, limit) {
+      (Some(category_id), Some(limit)) => {
+        data.categories.insert(category_id.to_string(), limit);
+      }
+      (Some(category_id), None) => {
+        data.categories.remove(category_id);
+      }
+      (None, limit) => data.overall = limit,
+    }
+
+    self.write(data)
+  }
+}
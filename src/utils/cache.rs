@@ -0,0 +1,258 @@
+use std::{collections::BTreeMap, env, fs::File, io::BufReader, path::PathBuf};
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  enums::{pending_operation::PendingOperation, transaction_type::TransactionType},
+  models::{category::CategoryModel, custom_error::CustomError, transaction::TransactionModel},
+  states::loader::MonthData,
+  storage::local::new_id,
+  types::{
+    request::transaction::CreateTransactionRequest, responses::transaction::MonthByYear,
+    year_month::YearMonth, Config,
+  },
+};
+
+use super::{fs::write_private, transaction_utils};
+
+/// Prefix of the ids given to transactions created offline until the server
+/// assigns the real ones.
+pub const PENDING_ID_PREFIX: &str = "pending-";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedMonth {
+  pub data: MonthData,
+  pub fetched_at: DateTime<Local>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheData {
+  pub months_by_year: Vec<MonthByYear>,
+  pub categories: Vec<CategoryModel>,
+  /// Keyed by `YearMonth` formatted as `YYYY-MM`, which also sorts chronologically.
+  pub months: BTreeMap<String, CachedMonth>,
+  pub pending: Vec<PendingOperation>,
+}
+
+/// On-disk copy of the last data fetched from the server, used to browse offline.
+pub struct Cache {
+  path: Option<PathBuf>,
+  pub data: CacheData,
+}
+
+/// One file per server and account, so logging in as someone else never shows
+/// or replays the previous account's data.
+fn cache_path(config: &Config) -> PathBuf {
+  let dir = match env::var("XDG_CACHE_HOME") {
+    Ok(dir) if !dir.is_empty() => PathBuf::from(dir),
+    _ => PathBuf::from(env::var("HOME").unwrap_or_default()).join(".cache"),
+  };
+
+  let key: String = format!(
+    "{}-{}",
+    config.url,
+    config.user_id.as_deref().unwrap_or_default()
+  )
+  .chars()
+  .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+  .collect();
+
+  dir.join("expenses").join(format!("cache-{}.json", key))
+}
+
+impl Cache {
+  /// Loads the cache file, a missing or unreadable cache starts empty.
+  pub fn load(config: &Config) -> Self {
+    let path = cache_path(config);
+
+    let data = File::open(&path)
+      .ok()
+      .and_then(|file| serde_json::from_reader(BufReader::new(file)).ok())
+      .unwrap_or_default();
+
+    Self {
+      path: Some(path),
+      data,
+    }
+  }
+
+  /// A cache that is never written to disk, for backends that are always available.
+  pub fn disabled() -> Self {
+    Self {
+      path: None,
+      data: CacheData::default(),
+    }
+  }
+
+  pub fn save(&self) -> Result<(), CustomError> {
+    if let Some(path) = &self.path {
+      write_private(path, &serde_json::to_vec(&self.data)?)?;
+    }
+
+    Ok(())
+  }
+
+  pub fn has_data(&self) -> bool {
+    !self.data.months.is_empty()
+  }
+
+  pub fn month(&self, period: &YearMonth) -> Option<&CachedMonth> {
+    self.data.months.get(&period.to_string())
+  }
+
+  /// The most recent month in the cache.
+  pub fn latest_month(&self) -> Option<(YearMonth, &CachedMonth)> {
+    self
+      .data
+      .months
+      .iter()
+      .rev()
+      .find_map(|(key, month)| key.parse().ok().map(|period| (period, month)))
+  }
+
+  /// Queues `operation` and applies it to the cached months, so the change is
+  /// shown before it reaches the server.
+  pub fn queue(&mut self, operation: PendingOperation) {
+    match &operation {
+      PendingOperation::CreateTransaction(request) => {
+        let transaction_id = format!("{}{}", PENDING_ID_PREFIX, new_id());
+        self.insert_transaction(transaction_id, request.clone());
+      }
+      PendingOperation::UpdateTransaction {
+        transaction_id,
+        request,
+      } => {
+        for month in self.data.months.values_mut() {
+          for transactions in [
+            &mut month.data.expenses,
+            &mut month.data.incomes,
+            &mut month.data.savings,
+          ] {
+            transactions.retain(|transaction| &transaction.transaction_id != transaction_id);
+          }
+        }
+
+        self.insert_transaction(transaction_id.clone(), request.clone());
+      }
+    }
+
+    self.data.pending.push(operation);
+  }
+
+  fn insert_transaction(&mut self, transaction_id: String, request: CreateTransactionRequest) {
+    let category = request.category_id.as_ref().and_then(|category_id| {
+      self
+        .data
+        .categories
+        .iter()
+        .find(|category| &category.category_id == category_id)
+        .cloned()
+    });
+    let transaction = TransactionModel {
+      transaction_id,
+      category,
+      r#type: request.r#type,
+      amount: request.amount,
+      currency: request.currency,
+      note: request.note,
+      day: request.day,
+      month: request.month,
+      year: request.year,
+      exchange_rate: request.exchange_rate,
+      user_id: String::new(),
+      user: None,
+      category_id: request.category_id.unwrap_or_default(),
+    };
+
+    let month = self
+      .data
+      .months
+      .entry(transaction.period().to_string())
+      .or_insert_with(|| CachedMonth {
+        data: MonthData::default(),
+        fetched_at: Local::now(),
+      });
+    let transactions = match transaction.r#type {
+      TransactionType::INCOME => &mut month.data.incomes,
+      TransactionType::SAVING => &mut month.data.savings,
+      TransactionType::EXPENSE | TransactionType::INSTALLMENTS => &mut month.data.expenses,
+    };
+
+    transactions.push(transaction);
+    transaction_utils::sort(transactions);
+  }
+
+  pub fn store_month(&mut self, period: &YearMonth, data: MonthData) {
+    self.data.months.insert(
+      period.to_string(),
+      CachedMonth {
+        data,
+        fetched_at: Local::now(),
+      },
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rust_decimal::Decimal;
+
+  use crate::enums::{currency::CurrencyEnum, month::MonthEnum};
+
+  use super::*;
+
+  fn request(r#type: TransactionType, amount: i64, month: MonthEnum) -> CreateTransactionRequest {
+    CreateTransactionRequest {
+      r#type,
+      amount: Decimal::from(amount),
+      currency: CurrencyEnum::UYU,
+      exchange_rate: None,
+      day: Some(5),
+      month,
+      year: 2023,
+      note: None,
+      category_id: None,
+    }
+  }
+
+  #[test]
+  fn queued_operations_are_shown_in_the_cached_months() {
+    let mut cache = Cache::disabled();
+    let march = YearMonth::new(2023, MonthEnum::MARCH);
+    let april = YearMonth::new(2023, MonthEnum::APRIL);
+
+    cache.queue(PendingOperation::CreateTransaction(request(
+      TransactionType::EXPENSE,
+      100,
+      MonthEnum::MARCH,
+    )));
+
+    let created = cache.month(&march).unwrap().data.expenses[0].clone();
+    assert!(created.transaction_id.starts_with(PENDING_ID_PREFIX));
+    assert_eq!(created.amount, Decimal::from(100));
+
+    cache.store_month(
+      &april,
+      MonthData {
+        expenses: vec![TransactionModel {
+          transaction_id: String::from("1"),
+          ..created
+        }],
+        ..MonthData::default()
+      },
+    );
+    cache.queue(PendingOperation::UpdateTransaction {
+      transaction_id: String::from("1"),
+      request: request(TransactionType::INCOME, 250, MonthEnum::APRIL),
+    });
+
+    let april = &cache.month(&april).unwrap().data;
+    assert!(april.expenses.is_empty());
+    assert_eq!(april.incomes[0].transaction_id, "1");
+    assert_eq!(april.incomes[0].amount, Decimal::from(250));
+    assert_eq!(cache.data.pending.len(), 2);
+  }
+}
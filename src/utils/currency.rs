@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+
+use crate::{
+  enums::currency::CurrencyEnum,
+  models::transaction::TransactionModel,
+  types::{money::Money, responses::transaction::TransactionTotal, Config},
+};
+
+/// Sum of converted amounts, `missing` counts the ones left out for lack of a rate.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Conversion {
+  pub amount: Decimal,
+  pub missing: usize,
+}
+
+/// Converts amounts to the display currency. Rates are UYU per unit, the same
+/// way transactions store their `exchange_rate`.
+#[derive(Debug, Clone, Default)]
+pub struct Converter {
+  pub display: CurrencyEnum,
+  rates: HashMap<CurrencyEnum, Decimal>,
+}
+
+impl Converter {
+  pub fn new(display: CurrencyEnum, rates: HashMap<CurrencyEnum, Decimal>) -> Self {
+    Self { display, rates }
+  }
+
+  pub fn from_config(config: &Config) -> Self {
+    Self::new(
+      config.display_currency.clone(),
+      config.exchange_rates.clone(),
+    )
+  }
+
+  pub fn cycle_display(&mut self) {
+    self.display = self.display.next();
+  }
+
+  /// The transaction's own rate wins over the fallback table from the config.
+  fn rate(&self, currency: &CurrencyEnum, transaction_rate: Option<Decimal>) -> Option<Decimal> {
+    match currency {
+      CurrencyEnum::UYU => Some(Decimal::ONE),
+      _ => transaction_rate
+        .or_else(|| self.rates.get(currency).copied())
+        .filter(|rate| *rate > Decimal::ZERO),
+    }
+  }
+
+  pub fn convert(
+    &self,
+    amount: Decimal,
+    from: &CurrencyEnum,
+    rate: Option<Decimal>,
+  ) -> Option<Decimal> {
+    self.convert_to(amount, from, rate, &self.display)
+  }
+
+  pub fn convert_to(
+    &self,
+    amount: Decimal,
+    from: &CurrencyEnum,
+    rate: Option<Decimal>,
+    to: &CurrencyEnum,
+  ) -> Option<Decimal> {
+    if from == to {
+      return Some(amount);
+    }
+
+    let uyu = amount * self.rate(from, rate)?;
+
+    uyu.checked_div(self.rate(to, None)?)
+  }
+
+  /// Converts a UYU total reported by the server, kept in UYU when there is
+  /// no rate for the display currency.
+  pub fn convert_uyu(&self, amount: Decimal) -> Money {
+    match self.convert(amount, &CurrencyEnum::UYU, None) {
+      Some(converted) => Money::new(converted, self.display.clone()),
+      None => Money::new(amount, CurrencyEnum::UYU),
+    }
+  }
+
+  pub fn convert_transaction(&self, transaction: &TransactionModel) -> Option<Decimal> {
+    self.convert(
+      transaction.amount,
+      &transaction.currency,
+      transaction.exchange_rate,
+    )
+  }
+
+  /// Converts every currency of `total` with the fallback rates.
+  pub fn convert_total(&self, total: &TransactionTotal) -> Conversion {
+    total.currencies.iter().fold(
+      Conversion::default(),
+      |mut conversion, (currency, amount)| {
+        match self.convert(*amount, currency, None) {
+          Some(amount) => conversion.amount += amount,
+          None => conversion.missing += 1,
+        }
+
+        conversion
+      },
+    )
+  }
+
+  pub fn total<'a>(
+    &self,
+    transactions: impl IntoIterator<Item = &'a TransactionModel>,
+  ) -> Conversion {
+    self.total_in(transactions, &self.display)
+  }
+
+  pub fn total_in<'a>(
+    &self,
+    transactions: impl IntoIterator<Item = &'a TransactionModel>,
+    currency: &CurrencyEnum,
+  ) -> Conversion {
+    transactions
+      .into_iter()
+      .fold(Conversion::default(), |mut total, transaction| {
+        match self.convert_to(
+          transaction.amount,
+          &transaction.currency,
+          transaction.exchange_rate,
+          currency,
+        ) {
+          Some(amount) => total.amount += amount,
+          None => total.missing += 1,
+        }
+
+        total
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{enums::transaction_type::TransactionType::EXPENSE, utils::fixtures::transaction};
+
+  use super::*;
+
+  use CurrencyEnum::*;
+
+  fn converter(display: CurrencyEnum) -> Converter {
+    Converter::new(
+      display,
+      HashMap::from([(USD, Decimal::from(40)), (EUR, Decimal::from(50))]),
+    )
+  }
+
+  #[test]
+  fn same_currency_is_kept_without_a_rate() {
+    let converter = Converter::new(USD, HashMap::new());
+
+    assert_eq!(
+      converter.convert(Decimal::from(12), &USD, None),
+      Some(Decimal::from(12))
+    );
+  }
+
+  #[test]
+  fn the_transaction_rate_wins_over_the_config() {
+    let converter = converter(UYU);
+
+    assert_eq!(
+      converter.convert(Decimal::from(10), &USD, None),
+      Some(Decimal::from(400))
+    );
+    assert_eq!(
+      converter.convert(Decimal::from(10), &USD, Some(Decimal::from(39))),
+      Some(Decimal::from(390))
+    );
+    assert_eq!(
+      converter.convert_uyu(Decimal::from(400)),
+      Money::new(Decimal::from(400), UYU)
+    );
+  }
+
+  #[test]
+  fn foreign_currencies_are_converted_through_uyu() {
+    let converter = converter(EUR);
+
+    assert_eq!(
+      converter.convert(Decimal::from(10), &USD, None),
+      Some(Decimal::from(8))
+    );
+    assert_eq!(
+      converter.convert_uyu(Decimal::from(100)),
+      Money::new(Decimal::from(2), EUR)
+    );
+  }
+
+  #[test]
+  fn amounts_without_a_rate_are_counted_as_missing() {
+    let converter = converter(USD);
+
+    assert_eq!(converter.convert(Decimal::from(10), &BRL, None), None);
+
+    let total = converter.total(&[
+      transaction(EXPENSE, 10).currency(USD).build(),
+      transaction(EXPENSE, 400).build(),
+      transaction(EXPENSE, 10).currency(BRL).build(),
+      transaction(EXPENSE, 5)
+        .currency(BRL)
+        .exchange_rate(8)
+        .build(),
+    ]);
+    assert_eq!(total.amount, Decimal::from(21));
+    assert_eq!(total.missing, 1);
+
+    let converter = Converter::new(BRL, HashMap::new());
+    assert_eq!(
+      converter.convert_uyu(Decimal::from(100)),
+      Money::new(Decimal::from(100), UYU)
+    );
+  }
+}
@@ -0,0 +1,295 @@
+use std::{collections::BTreeSet, path::Path};
+
+use serde::Serialize;
+
+use crate::{
+  enums::{currency::CurrencyEnum, export_format::ExportFormat},
+  models::{custom_error::CustomError, transaction::TransactionModel},
+  states::loader::MonthData,
+  types::{
+    responses::transaction::{TransactionBalances, TransactionTotal},
+    year_month::YearMonth,
+  },
+};
+
+use super::fs::write_private;
+
+const CSV_HEADERS: [&str; 9] = [
+  "year",
+  "month",
+  "day",
+  "type",
+  "amount",
+  "currency",
+  "exchange_rate",
+  "category",
+  "note",
+];
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MonthReport<'a> {
+  period: String,
+  balances: &'a TransactionBalances,
+  expenses: &'a [TransactionModel],
+  incomes: &'a [TransactionModel],
+  savings: &'a [TransactionModel],
+}
+
+fn balance_sections(summary: &TransactionBalances) -> [(&'static str, &TransactionTotal); 3] {
+  [
+    ("INCOMES", &summary.incomes),
+    ("EXPENSES", &summary.expenses),
+    ("SAVINGS", &summary.savings),
+  ]
+}
+
+fn optional<T: ToString>(value: &Option<T>) -> String {
+  value.as_ref().map(T::to_string).unwrap_or_default()
+}
+
+fn category_name(transaction: &TransactionModel) -> String {
+  transaction
+    .category
+    .as_ref()
+    .map(|category| category.name.clone())
+    .unwrap_or_default()
+}
+
+/// One row per transaction, followed by `BALANCE` rows holding each month's
+/// totals per currency with the balance kind in the category column.
+fn to_csv(months: &[(YearMonth, MonthData)]) -> Result<String, CustomError> {
+  let mut writer = csv::Writer::from_writer(Vec::new());
+  let csv_error = |err: csv::Error| CustomError::Io(err.into());
+
+  writer.write_record(CSV_HEADERS).map_err(csv_error)?;
+
+  for (period, data) in months {
+    for transaction in data
+      .incomes
+      .iter()
+      .chain(&data.expenses)
+      .chain(&data.savings)
+    {
+      writer
+        .write_record([
+          transaction.year.to_string(),
+          String::from(transaction.month.clone()),
+          optional(&transaction.day),
+          String::from(transaction.r#type.clone()),
+          transaction.amount.to_string(),
+          String::from(transaction.currency.clone()),
+          optional(&transaction.exchange_rate),
+          category_name(transaction),
+          transaction.note.clone().unwrap_or_default(),
+        ])
+        .map_err(csv_error)?;
+    }
+
+    for (kind, total) in balance_sections(&data.summary) {
+      for (currency, amount) in &total.currencies {
+        writer
+          .write_record([
+            period.year.to_string(),
+            String::from(period.month.clone()),
+            String::new(),
+            String::from("BALANCE"),
+            amount.to_string(),
+            currency.to_string(),
+            String::new(),
+            kind.to_string(),
+            String::new(),
+          ])
+          .map_err(csv_error)?;
+      }
+    }
+  }
+
+  let bytes = writer
+    .into_inner()
+    .map_err(|err| CustomError::Io(err.into_error()))?;
+
+  String::from_utf8(bytes).map_err(|err| CustomError::Decode(err.to_string()))
+}
+
+fn to_json(months: &[(YearMonth, MonthData)]) -> Result<String, CustomError> {
+  let reports: Vec<MonthReport> = months
+    .iter()
+    .map(|(period, data)| MonthReport {
+      period: period.to_string(),
+      balances: &data.summary,
+      expenses: &data.expenses,
+      incomes: &data.incomes,
+      savings: &data.savings,
+    })
+    .collect();
+
+  Ok(serde_json::to_string_pretty(&reports)?)
+}
+
+fn escape_markdown(value: &str) -> String {
+  value.replace('|', "\\|").replace('\n', " ")
+}
+
+fn markdown_transactions(report: &mut String, title: &str, transactions: &[TransactionModel]) {
+  report.push_str(&format!("\n### {}\n\n", title));
+
+  if transactions.is_empty() {
+    report.push_str("_No transactions_\n");
+    return;
+  }
+
+  report.push_str("| Day | Amount | Currency | Category | Note |\n");
+  report.push_str("| ---: | ---: | --- | --- | --- |\n");
+
+  for transaction in transactions {
+    report.push_str(&format!(
+      "| {} | {} | {} | {} | {} |\n",
+      optional(&transaction.day),
+      transaction.amount,
+      String::from(transaction.currency.clone()),
+      escape_markdown(&category_name(transaction)),
+      escape_markdown(&transaction.note.clone().unwrap_or_default()),
+    ));
+  }
+}
+
+fn to_markdown(months: &[(YearMonth, MonthData)]) -> String {
+  let mut report = match (months.first(), months.last()) {
+    (Some((first, _)), Some((last, _))) if first != last => {
+      format!("# Expenses report {} to {}\n", first, last)
+    }
+    (Some((period, _)), _) => format!("# Expenses report {}\n", period),
+    _ => String::from("# Expenses report\n"),
+  };
+
+  for (period, data) in months {
+    let sections = balance_sections(&data.summary);
+    let currencies: BTreeSet<&CurrencyEnum> = sections
+      .iter()
+      .flat_map(|(_, total)| total.currencies.keys())
+      .collect();
+
+    report.push_str(&format!("\n## {}\n\n", period));
+    report.push_str("| | Total (UYU) |");
+    for currency in &currencies {
+      report.push_str(&format!(" {} |", currency));
+    }
+    report.push_str("\n| --- | ---: |");
+    report.push_str(&" ---: |".repeat(currencies.len()));
+    report.push('\n');
+
+    for (kind, total) in sections {
+      report.push_str(&format!("| {} | {} |", kind, total.total));
+      for currency in &currencies {
+        report.push_str(&format!(" {} |", total.amount(currency)));
+      }
+      report.push('\n');
+    }
+
+    markdown_transactions(&mut report, "Incomes", &data.incomes);
+    markdown_transactions(&mut report, "Expenses", &data.expenses);
+    markdown_transactions(&mut report, "Savings", &data.savings);
+  }
+
+  report
+}
+
+pub fn render(
+  format: &ExportFormat,
+  months: &[(YearMonth, MonthData)],
+) -> Result<String, CustomError> {
+  match format {
+    ExportFormat::Csv => to_csv(months),
+    ExportFormat::Json => to_json(months),
+    ExportFormat::Markdown => Ok(to_markdown(months)),
+  }
+}
+
+pub fn write(
+  path: &Path,
+  format: &ExportFormat,
+  months: &[(YearMonth, MonthData)],
+) -> Result<(), CustomError> {
+  write_private(path, render(format, months)?.as_bytes())?;
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use rust_decimal::Decimal;
+
+  use crate::{
+    enums::transaction_type::TransactionType::*,
+    utils::fixtures::{month, transaction},
+  };
+
+  use super::*;
+
+  fn months() -> Vec<(YearMonth, MonthData)> {
+    let expense = TransactionModel {
+      amount: Decimal::new(12345, 1),
+      ..transaction(EXPENSE, 0)
+        .note("Rent, \"March\"")
+        .day(Some(5))
+        .category("Home")
+        .build()
+    };
+    let income = transaction(INCOME, 100)
+      .currency(CurrencyEnum::USD)
+      .exchange_rate(40)
+      .day(None)
+      .build();
+
+    let mut summary = TransactionBalances::default();
+    summary.expenses.add(&CurrencyEnum::UYU, expense.amount);
+    summary.expenses.total = expense.amount;
+    summary.incomes.add(&CurrencyEnum::USD, income.amount);
+    summary.incomes.total = Decimal::from(4000);
+
+    vec![(
+      month("2023-03"),
+      MonthData {
+        expenses: vec![expense],
+        incomes: vec![income],
+        summary,
+        ..MonthData::default()
+      },
+    )]
+  }
+
+  #[test]
+  fn csv_lists_transactions_then_balances_and_quotes_notes() {
+    let csv = render(&ExportFormat::Csv, &months()).unwrap();
+
+    assert_eq!(
+      csv.lines().collect::<Vec<&str>>(),
+      vec![
+        "year,month,day,type,amount,currency,exchange_rate,category,note",
+        "2023,MARCH,,INCOME,100,USD,40,,",
+        "2023,MARCH,5,EXPENSE,1234.5,UYU,,Home,\"Rent, \"\"March\"\"\"",
+        "2023,MARCH,,BALANCE,100,USD,,INCOMES,",
+        "2023,MARCH,,BALANCE,1234.5,UYU,,EXPENSES,",
+      ]
+    );
+  }
+
+  #[test]
+  fn json_keeps_exact_amounts_and_escapes_notes() {
+    let json = render(&ExportFormat::Json, &months()).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let report = &value[0];
+
+    assert_eq!(report["period"], "2023-03");
+    assert_eq!(report["expenses"][0]["note"], "Rent, \"March\"");
+    assert_eq!(report["expenses"][0]["amount"].to_string(), "1234.5");
+    assert_eq!(report["incomes"][0]["exchangeRate"].to_string(), "40");
+    assert_eq!(report["balances"]["incomes"]["usd"].to_string(), "100");
+    assert_eq!(
+      report["balances"]["expenses"]["total"].to_string(),
+      "1234.5"
+    );
+    assert_eq!(report["savings"], serde_json::json!([]));
+  }
+}
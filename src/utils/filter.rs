@@ -0,0 +1,199 @@
+use rust_decimal::Decimal;
+
+use crate::{enums::currency::CurrencyEnum, models::transaction::TransactionModel};
+
+/// Criteria the transaction tables are filtered by, all of them must match.
+/// Amounts are compared in the transaction's own currency.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionFilter {
+  /// Matched against the note, category and amount as the table shows them, set by the `/` search.
+  pub text: String,
+  pub category: String,
+  pub min: Option<Decimal>,
+  pub max: Option<Decimal>,
+  pub currency: Option<CurrencyEnum>,
+  pub days: Option<(u8, u8)>,
+}
+
+fn contains(value: &str, needle: &str) -> bool {
+  value.to_lowercase().contains(needle)
+}
+
+impl TransactionFilter {
+  pub fn is_empty(&self) -> bool {
+    self.text.trim().is_empty()
+      && self.category.trim().is_empty()
+      && self.min.is_none()
+      && self.max.is_none()
+      && self.currency.is_none()
+      && self.days.is_none()
+  }
+
+  /// Whether `text` appears in any of the rendered `cells`, so what matches is
+  /// also what gets highlighted.
+  pub fn matches_text(&self, cells: &[String]) -> bool {
+    let text = self.text.trim().to_lowercase();
+
+    text.is_empty() || cells.iter().any(|cell| contains(cell, &text))
+  }
+
+  /// Checks every criterion but the text, see `matches_text`.
+  pub fn matches(&self, transaction: &TransactionModel) -> bool {
+    let category = transaction
+      .category
+      .as_ref()
+      .map(|category| category.name.as_str())
+      .unwrap_or_default();
+
+    let needle = self.category.trim().to_lowercase();
+    let matches_category = needle.is_empty() || contains(category, &needle);
+
+    let matches_days = match (self.days, transaction.day) {
+      (Some((from, to)), Some(day)) => (from..=to).contains(&day),
+      (Some(_), None) => false,
+      (None, _) => true,
+    };
+
+    matches_category
+      && matches_days
+      && self.min.is_none_or(|min| transaction.amount >= min)
+      && self.max.is_none_or(|max| transaction.amount <= max)
+      && self
+        .currency
+        .as_ref()
+        .is_none_or(|currency| transaction.currency == *currency)
+  }
+
+  /// Short summary of the active criteria for the status bar.
+  pub fn describe(&self) -> String {
+    let mut parts: Vec<String> = Vec::new();
+
+    if !self.text.trim().is_empty() {
+      parts.push(format!("\"{}\"", self.text.trim()));
+    }
+
+    if !self.category.trim().is_empty() {
+      parts.push(format!("category {}", self.category.trim()));
+    }
+
+    match (self.min, self.max) {
+      (Some(min), Some(max)) => parts.push(format!("amount {}..{}", min, max)),
+      (Some(min), None) => parts.push(format!("amount >= {}", min)),
+      (None, Some(max)) => parts.push(format!("amount <= {}", max)),
+      (None, None) => (),
+    }
+
+    if let Some(currency) = &self.currency {
+      parts.push(currency.to_string());
+    }
+
+    match self.days {
+      Some((from, to)) if from == to => parts.push(format!("day {}", from)),
+      Some((from, to)) => parts.push(format!("days {}-{}", from, to)),
+      None => (),
+    }
+
+    parts.join(", ")
+  }
+}
+
+/// Parses a day like `5` or a range like `1-15`.
+pub fn parse_days(value: &str) -> Result<Option<(u8, u8)>, String> {
+  let value = value.trim();
+
+  if value.is_empty() {
+    return Ok(None);
+  }
+
+  let (from, to) = value.split_once('-').unwrap_or((value, value));
+
+  match (from.trim().parse::<u8>(), to.trim().parse::<u8>()) {
+    (Ok(from), Ok(to)) if (1..=31).contains(&from) && (from..=31).contains(&to) => {
+      Ok(Some((from, to)))
+    }
+    _ => Err(String::from("Day must be like 5 or 1-15")),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{enums::transaction_type::TransactionType, utils::fixtures};
+
+  use super::*;
+
+  fn transaction(amount: i64, currency: CurrencyEnum, day: Option<u8>) -> TransactionModel {
+    fixtures::transaction(TransactionType::EXPENSE, amount)
+      .currency(currency)
+      .day(day)
+      .note("Groceries")
+      .category("Food")
+      .build()
+  }
+
+  #[test]
+  fn empty_filter_matches_everything() {
+    let filter = TransactionFilter::default();
+
+    assert!(filter.is_empty());
+    assert!(filter.matches(&transaction(10, CurrencyEnum::UYU, None)));
+    assert!(filter.matches_text(&[String::from("anything")]));
+  }
+
+  #[test]
+  fn text_matches_the_rendered_cells() {
+    let filter = TransactionFilter {
+      text: String::from("1,234"),
+      ..TransactionFilter::default()
+    };
+    let cells = [String::from("$1,234.50 UYU"), String::from("Rent")];
+
+    assert!(filter.matches_text(&cells));
+    assert!(!filter.matches_text(&[String::from("$1.234,50 UYU")]));
+
+    let filter = TransactionFilter {
+      text: String::from("RENT"),
+      ..TransactionFilter::default()
+    };
+    assert!(filter.matches_text(&cells));
+  }
+
+  #[test]
+  fn criteria_are_combined() {
+    let filter = TransactionFilter {
+      category: String::from("foo"),
+      min: Some(Decimal::from(10)),
+      max: Some(Decimal::from(100)),
+      currency: Some(CurrencyEnum::USD),
+      days: Some((1, 15)),
+      ..TransactionFilter::default()
+    };
+
+    assert!(filter.matches(&transaction(50, CurrencyEnum::USD, Some(5))));
+    assert!(!filter.matches(&transaction(50, CurrencyEnum::UYU, Some(5))));
+    assert!(!filter.matches(&transaction(500, CurrencyEnum::USD, Some(5))));
+    assert!(!filter.matches(&transaction(5, CurrencyEnum::USD, Some(5))));
+    assert!(!filter.matches(&transaction(50, CurrencyEnum::USD, Some(20))));
+    assert!(!filter.matches(&transaction(50, CurrencyEnum::USD, None)));
+
+    let filter = TransactionFilter {
+      category: String::from("travel"),
+      ..TransactionFilter::default()
+    };
+    assert!(!filter.matches(&transaction(50, CurrencyEnum::USD, Some(5))));
+  }
+
+  #[test]
+  fn parse_days_accepts_a_day_or_a_range() {
+    assert_eq!(parse_days(""), Ok(None));
+    assert_eq!(parse_days("5"), Ok(Some((5, 5))));
+    assert_eq!(parse_days(" 1 - 15 "), Ok(Some((1, 15))));
+  }
+
+  #[test]
+  fn parse_days_rejects_invalid_days() {
+    assert!(parse_days("0").is_err());
+    assert!(parse_days("32").is_err());
+    assert!(parse_days("15-1").is_err());
+    assert!(parse_days("first").is_err());
+  }
+}
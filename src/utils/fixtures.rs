@@ -0,0 +1,89 @@
+use rust_decimal::Decimal;
+
+use crate::{
+  enums::{currency::CurrencyEnum, transaction_type::TransactionType},
+  models::{category::CategoryModel, transaction::TransactionModel},
+  types::year_month::YearMonth,
+};
+
+/// Parses a `YYYY-MM` month, e.g. `month("2023-03")`.
+pub fn month(value: &str) -> YearMonth {
+  value.parse().unwrap()
+}
+
+/// Sets only the fields a test cares about, see `transaction`.
+pub struct TransactionBuilder {
+  transaction: TransactionModel,
+}
+
+/// A transaction of `amount` UYU on March 10th, 2023, without note or category.
+pub fn transaction(r#type: TransactionType, amount: i64) -> TransactionBuilder {
+  let period = month("2023-03");
+
+  TransactionBuilder {
+    transaction: TransactionModel {
+      transaction_id: String::from("1"),
+      r#type,
+      amount: Decimal::from(amount),
+      currency: CurrencyEnum::UYU,
+      note: None,
+      day: Some(10),
+      month: period.month,
+      year: period.year as u16,
+      exchange_rate: None,
+      user_id: String::new(),
+      user: None,
+      category_id: String::new(),
+      category: None,
+    },
+  }
+}
+
+impl TransactionBuilder {
+  pub fn note(mut self, note: &str) -> Self {
+    self.transaction.note = Some(String::from(note));
+    self
+  }
+
+  pub fn currency(mut self, currency: CurrencyEnum) -> Self {
+    self.transaction.currency = currency;
+    self
+  }
+
+  pub fn exchange_rate(mut self, rate: i64) -> Self {
+    self.transaction.exchange_rate = Some(Decimal::from(rate));
+    self
+  }
+
+  pub fn day(mut self, day: Option<u8>) -> Self {
+    self.transaction.day = day;
+    self
+  }
+
+  /// A category of the transaction's type, its id is the lowercase name.
+  pub fn category(mut self, name: &str) -> Self {
+    let category_id = name.to_lowercase();
+
+    self.transaction.category_id = category_id.clone();
+    self.transaction.category = Some(CategoryModel {
+      category_id,
+      r#type: self.transaction.r#type.clone(),
+      name: String::from(name),
+      note: None,
+      user_id: String::new(),
+      user: None,
+    });
+    self
+  }
+
+  pub fn period(mut self, period: &str) -> Self {
+    let period = month(period);
+    self.transaction.month = period.month;
+    self.transaction.year = period.year as u16;
+    self
+  }
+
+  pub fn build(self) -> TransactionModel {
+    self.transaction
+  }
+}
@@ -0,0 +1,84 @@
+use std::{
+  fs::{self, File, OpenOptions},
+  io::{self, BufReader, ErrorKind, Write},
+  os::unix::fs::OpenOptionsExt,
+  path::{Path, PathBuf},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{config_path, models::custom_error::CustomError};
+
+/// Writes `contents` through a temporary file readable only by the owner, so
+/// a crash never leaves a truncated file behind.
+pub fn write_private(path: &Path, contents: &[u8]) -> io::Result<()> {
+  let tmp_path = path.with_extension("tmp");
+
+  if let Some(parent) = path.parent() {
+    fs::create_dir_all(parent)?;
+  }
+
+  let mut file = OpenOptions::new()
+    .write(true)
+    .create(true)
+    .truncate(true)
+    .mode(0o600)
+    .open(&tmp_path)?;
+
+  file.write_all(contents)?;
+  file.sync_all()?;
+
+  fs::rename(tmp_path, path)
+}
+
+/// Data kept in its own JSON file next to the config.
+pub trait JsonData: Serialize + DeserializeOwned + Default {
+  const FILE_NAME: &'static str;
+}
+
+/// A JSON file next to the config, a missing file starts from the default data.
+pub struct JsonFile<T> {
+  path: Option<PathBuf>,
+  pub data: T,
+}
+
+impl<T: JsonData> JsonFile<T> {
+  pub fn default_path() -> PathBuf {
+    PathBuf::from(config_path()).with_file_name(T::FILE_NAME)
+  }
+
+  pub fn load() -> Result<Self, CustomError> {
+    let path = Self::default_path();
+
+    let data = match File::open(&path) {
+      Ok(file) => serde_json::from_reader(BufReader::new(file))?,
+      Err(err) if err.kind() == ErrorKind::NotFound => T::default(),
+      Err(err) => return Err(err.into()),
+    };
+
+    Ok(Self {
+      path: Some(path),
+      data,
+    })
+  }
+
+  /// Data that is never written to disk, used when the file couldn't be read
+  /// so it isn't overwritten.
+  pub fn disabled() -> Self {
+    Self {
+      path: None,
+      data: T::default(),
+    }
+  }
+
+  /// Only keeps `data` once it was written to disk.
+  pub fn write(&mut self, data: T) -> Result<(), CustomError> {
+    if let Some(path) = &self.path {
+      write_private(path, &serde_json::to_vec_pretty(&data)?)?;
+    }
+
+    self.data = data;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,15 @@
+use crossterm::event::KeyCode;
+use tui_input::InputRequest;
+
+pub fn to_input_request(key_code: KeyCode) -> Option<InputRequest> {
+  match key_code {
+    KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
+    KeyCode::Delete => Some(InputRequest::DeleteNextChar),
+    KeyCode::Left => Some(InputRequest::GoToPrevChar),
+    KeyCode::Right => Some(InputRequest::GoToNextChar),
+    KeyCode::Home => Some(InputRequest::GoToStart),
+    KeyCode::End => Some(InputRequest::GoToEnd),
+    KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
+    _ => None,
+  }
+}
@@ -0,0 +1,322 @@
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+
+use crate::{
+  enums::{currency::CurrencyEnum, transaction_type::TransactionType},
+  models::transaction::TransactionModel,
+  types::{money::Money, year_month::YearMonth},
+};
+
+/// Payment number and plan length written at the end of a note, e.g. `TV 3/12`
+/// or `TV (3/12)`.
+pub struct Marker {
+  pub name: String,
+  pub number: u32,
+  pub count: u32,
+}
+
+pub fn parse_note(note: &str) -> Option<Marker> {
+  let note = note.trim();
+  let (name, last) = match note.rsplit_once(char::is_whitespace) {
+    Some((name, last)) => (name.trim(), last),
+    None => ("", note),
+  };
+
+  let (number, count) = last
+    .trim_start_matches('(')
+    .trim_end_matches(')')
+    .split_once('/')?;
+  let number: u32 = number.parse().ok()?;
+  let count: u32 = count.parse().ok()?;
+
+  if number == 0 || number > count {
+    return None;
+  }
+
+  Some(Marker {
+    name: name.to_string(),
+    number,
+    count,
+  })
+}
+
+/// Note shown in the transaction tables, installments are prefixed with their
+/// payment number so they stand out from one-off expenses.
+pub fn table_note(transaction: &TransactionModel) -> Option<String> {
+  let note = transaction.note.as_deref().unwrap_or_default();
+
+  if transaction.r#type != TransactionType::INSTALLMENTS {
+    return transaction.note.clone();
+  }
+
+  Some(match parse_note(note) {
+    Some(marker) => format!("[{}/{}] {}", marker.number, marker.count, marker.name),
+    None => format!("[installment] {}", note),
+  })
+}
+
+/// A purchase paid in equal monthly installments.
+#[derive(Debug, Clone)]
+pub struct InstallmentPlan {
+  pub name: String,
+  pub category: Option<String>,
+  pub payment: Decimal,
+  pub currency: CurrencyEnum,
+  pub exchange_rate: Option<Decimal>,
+  pub count: u32,
+  pub start: YearMonth,
+}
+
+impl InstallmentPlan {
+  pub fn end(&self) -> YearMonth {
+    self.start.add_months(self.count as i32 - 1)
+  }
+
+  /// Payments charged up to and including `now`.
+  pub fn paid(&self, now: &YearMonth) -> u32 {
+    (self.start.months_until(now) + 1).clamp(0, self.count as i32) as u32
+  }
+
+  pub fn remaining(&self, now: &YearMonth) -> u32 {
+    self.count - self.paid(now)
+  }
+
+  pub fn total(&self) -> Money {
+    Money::new(
+      self.payment * Decimal::from(self.count),
+      self.currency.clone(),
+    )
+  }
+
+  pub fn remaining_amount(&self, now: &YearMonth) -> Money {
+    Money::new(
+      self.payment * Decimal::from(self.remaining(now)),
+      self.currency.clone(),
+    )
+  }
+
+  pub fn charges_in(&self, period: &YearMonth) -> bool {
+    let index = self.start.months_until(period);
+    index >= 0 && index < self.count as i32
+  }
+}
+
+/// Installments without a marker are grouped by note and amount, the plan
+/// spanning from the first to the last payment found.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct PlanKey {
+  name: String,
+  currency: CurrencyEnum,
+  payment: Decimal,
+  schedule: Option<(YearMonth, u32)>,
+}
+
+/// Groups installment transactions into plans, the ones ending last first.
+pub fn plans<'a>(
+  transactions: impl IntoIterator<Item = &'a TransactionModel>,
+) -> Vec<InstallmentPlan> {
+  let mut groups: BTreeMap<PlanKey, (InstallmentPlan, YearMonth)> = BTreeMap::new();
+
+  for transaction in transactions {
+    if transaction.r#type != TransactionType::INSTALLMENTS {
+      continue;
+    }
+
+    let period = transaction.period();
+    let note = transaction.note.as_deref().unwrap_or_default().trim();
+    let marker = parse_note(note);
+
+    let name = match &marker {
+      Some(marker) => marker.name.clone(),
+      None => note.to_string(),
+    };
+    let schedule = marker.map(|marker| (period.add_months(1 - marker.number as i32), marker.count));
+
+    let marked = schedule.is_some();
+    let key = PlanKey {
+      name: name.to_lowercase(),
+      currency: transaction.currency.clone(),
+      payment: transaction.amount.normalize(),
+      schedule: schedule.clone(),
+    };
+
+    let (plan, last) = groups.entry(key).or_insert_with(|| {
+      let (start, count) = schedule.unwrap_or((period.clone(), 1));
+
+      let plan = InstallmentPlan {
+        name,
+        category: transaction.category.as_ref().map(|c| c.name.clone()),
+        payment: transaction.amount,
+        currency: transaction.currency.clone(),
+        exchange_rate: transaction.exchange_rate,
+        count,
+        start,
+      };
+
+      (plan, period.clone())
+    });
+
+    if period > *last {
+      *last = period.clone();
+      plan.exchange_rate = transaction.exchange_rate.or(plan.exchange_rate);
+    }
+
+    if !marked && plan.start.months_until(&period) < 0 {
+      plan.start = period;
+    }
+  }
+
+  let mut plans: Vec<InstallmentPlan> = groups
+    .into_iter()
+    .map(|(key, (mut plan, last))| {
+      if key.schedule.is_none() {
+        plan.count = (plan.start.months_until(&last) + 1) as u32;
+      }
+
+      if plan.name.is_empty() {
+        plan.name = plan
+          .category
+          .clone()
+          .unwrap_or_else(|| String::from("Installments"));
+      }
+
+      plan
+    })
+    .collect();
+
+  plans.sort_by(|a, b| b.end().cmp(&a.end()).then_with(|| a.name.cmp(&b.name)));
+  plans
+}
+
+/// Charges expected in each month after `now`, until the last plan ends.
+pub fn upcoming<'a>(
+  plans: &'a [InstallmentPlan],
+  now: &YearMonth,
+) -> Vec<(YearMonth, Vec<&'a InstallmentPlan>)> {
+  let Some(last) = plans.iter().map(InstallmentPlan::end).max() else {
+    return Vec::new();
+  };
+
+  (1..=now.months_until(&last))
+    .map(|offset| {
+      let period = now.add_months(offset);
+      let charges = plans
+        .iter()
+        .filter(|plan| plan.charges_in(&period))
+        .collect();
+      (period, charges)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::utils::fixtures::{month, transaction};
+
+  use super::*;
+
+  fn installment(note: &str, amount: i64, period: &str) -> TransactionModel {
+    transaction(TransactionType::INSTALLMENTS, amount)
+      .note(note)
+      .period(period)
+      .build()
+  }
+
+  #[test]
+  fn parse_note_reads_the_trailing_marker() {
+    let marker = parse_note("New TV 3/12").unwrap();
+    assert_eq!(
+      (marker.name.as_str(), marker.number, marker.count),
+      ("New TV", 3, 12)
+    );
+
+    let marker = parse_note(" Phone (1/6) ").unwrap();
+    assert_eq!(
+      (marker.name.as_str(), marker.number, marker.count),
+      ("Phone", 1, 6)
+    );
+
+    let marker = parse_note("2/3").unwrap();
+    assert_eq!(
+      (marker.name.as_str(), marker.number, marker.count),
+      ("", 2, 3)
+    );
+  }
+
+  #[test]
+  fn parse_note_rejects_invalid_markers() {
+    assert!(parse_note("New TV").is_none());
+    assert!(parse_note("TV 0/12").is_none());
+    assert!(parse_note("TV 13/12").is_none());
+    assert!(parse_note("TV a/12").is_none());
+    assert!(parse_note("").is_none());
+  }
+
+  #[test]
+  fn plans_project_marked_payments_from_any_month() {
+    let transactions = [installment("TV 3/12", 100, "2023-03")];
+    let plans = plans(&transactions);
+
+    assert_eq!(plans.len(), 1);
+    assert_eq!(plans[0].name, "TV");
+    assert_eq!(plans[0].start, month("2023-01"));
+    assert_eq!(plans[0].end(), month("2023-12"));
+    assert_eq!(plans[0].paid(&month("2023-03")), 3);
+    assert_eq!(
+      plans[0].remaining_amount(&month("2023-03")).amount,
+      Decimal::from(900)
+    );
+  }
+
+  #[test]
+  fn plans_group_payments_of_the_same_purchase() {
+    let transactions = [
+      installment("TV 3/12", 100, "2023-03"),
+      installment("tv 4/12", 100, "2023-04"),
+      installment("Phone", 50, "2023-04"),
+      installment("Phone", 50, "2023-02"),
+      installment("Phone", 50, "2023-03"),
+      installment("Phone", 70, "2023-03"),
+    ];
+    let plans = plans(&transactions);
+
+    assert_eq!(plans.len(), 3);
+    assert_eq!(plans[0].name, "TV");
+
+    let phone = plans
+      .iter()
+      .find(|plan| plan.payment == Decimal::from(50))
+      .unwrap();
+    assert_eq!(phone.start, month("2023-02"));
+    assert_eq!(phone.count, 3);
+    assert_eq!(phone.total().amount, Decimal::from(150));
+  }
+
+  #[test]
+  fn upcoming_lists_the_charges_of_each_following_month() {
+    let transactions = [
+      installment("TV 11/12", 100, "2023-11"),
+      installment("Phone 1/3", 50, "2023-11"),
+    ];
+    let plans = plans(&transactions);
+    let upcoming = upcoming(&plans, &month("2023-11"));
+
+    let names: Vec<(String, Vec<&str>)> = upcoming
+      .iter()
+      .map(|(period, plans)| {
+        let names = plans.iter().map(|plan| plan.name.as_str()).collect();
+        (period.to_string(), names)
+      })
+      .collect();
+
+    assert_eq!(
+      names,
+      vec![
+        (String::from("2023-12"), vec!["Phone", "TV"]),
+        (String::from("2024-01"), vec!["Phone"]),
+      ]
+    );
+    assert!(super::upcoming(&plans, &month("2024-01")).is_empty());
+  }
+}
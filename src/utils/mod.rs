@@ -1,15 +1,27 @@
-use chrono::{Local, Datelike};
 use tui_tree_widget::{TreeItem, TreeState};
 
-use crate::{enums::month::MonthEnum, types::responses::transaction::MonthByYear};
-
+use crate::{
+  enums::month::MonthEnum,
+  types::{responses::transaction::MonthByYear, year_month::YearMonth},
+};
+
+pub mod budgets;
+pub mod cache;
+pub mod currency;
+pub mod export;
+pub mod filter;
+#[cfg(test)]
+pub mod fixtures;
+pub mod fs;
+pub mod input;
+pub mod installments;
+pub mod recurring;
 pub mod transaction_utils;
 
 pub struct StatefulTree<'a> {
   pub state: TreeState,
   pub items: Vec<TreeItem<'a>>,
-  pub current_month: MonthEnum,
-  pub current_year: i32,
+  pub current: YearMonth,
 }
 
 impl<'a> StatefulTree<'a> {
@@ -17,25 +29,18 @@ impl<'a> StatefulTree<'a> {
   pub fn new() -> Self {
     let state: TreeState = TreeState::default();
 
-    let now = Local::now();
-    let month = now.month();
-
     Self {
       state,
       items: Vec::new(),
-      current_month: MonthEnum::from_u32(month).unwrap_or(MonthEnum::JANUARY),
-      current_year: now.year(),
+      current: YearMonth::now(),
     }
   }
 
   pub fn with_items(items: Vec<TreeItem<'a>>) -> Self {
-    let now = Local::now();
-
     Self {
       state: TreeState::default(),
       items,
-      current_month: MonthEnum::from_u32(now.month()).unwrap_or(MonthEnum::JANUARY),
-      current_year: now.year(),
+      current: YearMonth::now(),
     }
   }
 
@@ -63,23 +68,73 @@ impl<'a> StatefulTree<'a> {
     self.state.key_right();
   }
 
+  /// The year of the selected node when it is a year rather than a month.
+  pub fn selected_year(&self, months_by_year: &[MonthByYear]) -> Option<i32> {
+    match self.state.selected().as_slice() {
+      [index] => months_by_year.get(*index)?.year.parse().ok(),
+      _ => None,
+    }
+  }
+
   pub fn toggle(&mut self, months_by_year: &[MonthByYear]) {
     self.state.toggle_selected();
 
     let selected = self.state.selected();
     if !selected.is_empty() {
-      let year_index = selected[0];
-      if selected.len() > 1 {
-        let month_index = selected[1];
+      let Some(item) = months_by_year.get(selected[0]) else {
+        return;
+      };
+
+      let year = item.year.parse().unwrap_or(self.current.year);
+      let month = selected
+        .get(1)
+        .and_then(|index| item.months.get(*index))
+        .and_then(|month| MonthEnum::from_string(month.clone()))
+        .unwrap_or_else(|| self.current.month.clone());
+
+      self.current = YearMonth::new(year, month);
+    }
+  }
+}
 
-        let month = months_by_year[year_index].months[month_index].clone();
-        self.current_month = MonthEnum::from_string(month).unwrap_or(MonthEnum::JANUARY);
+pub fn wrap_text(text: &str, width: usize) -> String {
+  if width == 0 || text.chars().count() <= width {
+    return text.to_string();
+  }
+
+  let mut lines: Vec<String> = Vec::new();
+  let mut line = String::new();
+
+  for word in text.split_whitespace() {
+    let mut word: Vec<char> = word.chars().collect();
+
+    while word.len() > width {
+      if !line.is_empty() {
+        lines.push(std::mem::take(&mut line));
       }
+      lines.push(word.drain(..width).collect());
+    }
 
-      let year = months_by_year[year_index].year.clone();
-      self.current_year = year.parse().unwrap_or(2023);
+    let word: String = word.into_iter().collect();
+    if word.is_empty() {
+      continue;
     }
+
+    if line.is_empty() {
+      line = word;
+    } else if line.chars().count() + 1 + word.chars().count() <= width {
+      line.push(' ');
+      line.push_str(&word);
+    } else {
+      lines.push(std::mem::replace(&mut line, word));
+    }
+  }
+
+  if !line.is_empty() {
+    lines.push(line);
   }
+
+  lines.join("\n")
 }
 
 impl<'a> Default for StatefulTree<'a> {
@@ -0,0 +1,307 @@
+use std::collections::BTreeSet;
+
+use chrono::{Datelike, Duration, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  enums::{currency::CurrencyEnum, frequency::Frequency, transaction_type::TransactionType},
+  models::custom_error::CustomError,
+  types::{request::transaction::CreateTransactionRequest, year_month::YearMonth},
+};
+
+use super::fs::{JsonData, JsonFile};
+
+/// Months looked ahead when searching for the next occurrence, a yearly
+/// template always falls within them.
+const LOOKAHEAD_MONTHS: i32 = 13;
+
+fn first_day(period: &YearMonth) -> Option<NaiveDate> {
+  NaiveDate::from_ymd_opt(period.year, period.month.to_u32(), 1)
+}
+
+fn last_day(period: &YearMonth) -> Option<NaiveDate> {
+  first_day(&period.next())?.pred_opt()
+}
+
+/// `day` of the month, moved back to the last day on shorter months.
+fn clamped_day(period: &YearMonth, day: u32) -> Option<NaiveDate> {
+  let last = last_day(period)?;
+  NaiveDate::from_ymd_opt(period.year, period.month.to_u32(), day.min(last.day()))
+}
+
+/// A transaction entered on a fixed schedule, like rent or a salary.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecurringTemplate {
+  pub id: String,
+  pub r#type: TransactionType,
+  pub amount: Decimal,
+  pub currency: CurrencyEnum,
+  pub category_id: Option<String>,
+  pub note: Option<String>,
+  pub frequency: Frequency,
+  pub start: NaiveDate,
+  pub end: Option<NaiveDate>,
+  /// Occurrences already turned into transactions or skipped.
+  #[serde(default)]
+  pub generated: BTreeSet<NaiveDate>,
+}
+
+impl RecurringTemplate {
+  fn is_active(&self, date: &NaiveDate) -> bool {
+    *date >= self.start && self.end.is_none_or(|end| *date <= end)
+  }
+
+  /// Dates the template falls on within `period`.
+  pub fn occurrences(&self, period: &YearMonth) -> Vec<NaiveDate> {
+    let (Some(first), Some(last)) = (first_day(period), last_day(period)) else {
+      return Vec::new();
+    };
+
+    let dates = match self.frequency {
+      Frequency::MONTHLY => clamped_day(period, self.start.day()).into_iter().collect(),
+      Frequency::YEARLY if period.month.to_u32() == self.start.month() => {
+        clamped_day(period, self.start.day()).into_iter().collect()
+      }
+      Frequency::YEARLY => Vec::new(),
+      Frequency::WEEKLY => {
+        let offset = (first - self.start).num_days().rem_euclid(7);
+        let mut date = first + Duration::days((7 - offset) % 7);
+        let mut dates = Vec::new();
+
+        while date <= last {
+          dates.push(date);
+          date += Duration::days(7);
+        }
+
+        dates
+      }
+    };
+
+    dates
+      .into_iter()
+      .filter(|date| self.is_active(date))
+      .collect()
+  }
+
+  /// Occurrences of `period` that still have to be generated.
+  pub fn pending(&self, period: &YearMonth) -> Vec<NaiveDate> {
+    self
+      .occurrences(period)
+      .into_iter()
+      .filter(|date| !self.generated.contains(date))
+      .collect()
+  }
+
+  /// The first occurrence on or after `from`.
+  pub fn next_occurrence(&self, from: NaiveDate) -> Option<NaiveDate> {
+    let period = YearMonth::from_date(&from);
+
+    (0..LOOKAHEAD_MONTHS)
+      .flat_map(|offset| self.occurrences(&period.add_months(offset)))
+      .find(|date| *date >= from)
+  }
+
+  pub fn to_request(&self, date: NaiveDate) -> CreateTransactionRequest {
+    CreateTransactionRequest {
+      r#type: self.r#type.clone(),
+      amount: self.amount,
+      currency: self.currency.clone(),
+      exchange_rate: None,
+      day: Some(date.day() as u8),
+      month: YearMonth::from_date(&date).month,
+      year: date.year() as u16,
+      note: self.note.clone(),
+      category_id: self.category_id.clone(),
+    }
+  }
+}
+
+/// An occurrence of a template waiting to be turned into a transaction.
+#[derive(Debug, Clone)]
+pub struct Occurrence {
+  pub template_id: String,
+  pub date: NaiveDate,
+  pub request: CreateTransactionRequest,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecurringData {
+  pub templates: Vec<RecurringTemplate>,
+}
+
+impl JsonData for RecurringData {
+  const FILE_NAME: &'static str = "recurring.json";
+}
+
+/// Recurring templates, kept in a file next to the config so they work with
+/// every backend.
+pub type Recurring = JsonFile<RecurringData>;
+
+impl Recurring {
+  /// Adds the template or replaces the one with the same id.
+  pub fn save(&mut self, template: RecurringTemplate) -> Result<(), CustomError> {
+    let mut data = self.data.clone();
+
+    match data
+      .templates
+      .iter_mut()
+      .find(|known| known.id == template.id)
+    {
+      Some(known) => *known = template,
+      None => data.templates.push(template),
+    }
+
+    self.write(data)
+  }
+
+  pub fn remove(&mut self, id: &str) -> Result<(), CustomError> {
+    let mut data = self.data.clone();
+    data.templates.retain(|template| template.id != id);
+
+    self.write(data)
+  }
+
+  pub fn mark_generated(&mut self, occurrences: &[Occurrence]) -> Result<(), CustomError> {
+    let mut data = self.data.clone();
+
+    for occurrence in occurrences {
+      if let Some(template) = data
+        .templates
+        .iter_mut()
+        .find(|template| template.id == occurrence.template_id)
+      {
+        template.generated.insert(occurrence.date);
+      }
+    }
+
+    self.write(data)
+  }
+
+  /// Occurrences of every template in `period` that still have to be generated.
+  pub fn pending(&self, period: &YearMonth) -> Vec<Occurrence> {
+    self
+      .data
+      .templates
+      .iter()
+      .flat_map(|template| {
+        template.pending(period).into_iter().map(|date| Occurrence {
+          template_id: template.id.clone(),
+          date,
+          request: template.to_request(date),
+        })
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::utils::fixtures::month;
+
+  use super::*;
+
+  fn date(value: &str) -> NaiveDate {
+    value.parse().unwrap()
+  }
+
+  fn template(frequency: Frequency, start: &str) -> RecurringTemplate {
+    RecurringTemplate {
+      id: String::from("1"),
+      r#type: TransactionType::EXPENSE,
+      amount: Decimal::from(100),
+      currency: CurrencyEnum::UYU,
+      category_id: None,
+      note: Some(String::from("Rent")),
+      frequency,
+      start: date(start),
+      end: None,
+      generated: BTreeSet::new(),
+    }
+  }
+
+  fn dates(values: &[&str]) -> Vec<NaiveDate> {
+    values.iter().map(|value| date(value)).collect()
+  }
+
+  #[test]
+  fn monthly_on_the_31st_falls_on_the_last_day_of_short_months() {
+    let template = template(Frequency::MONTHLY, "2023-01-31");
+
+    assert_eq!(
+      template.occurrences(&month("2023-02")),
+      dates(&["2023-02-28"])
+    );
+    assert_eq!(
+      template.occurrences(&month("2024-02")),
+      dates(&["2024-02-29"])
+    );
+    assert_eq!(
+      template.occurrences(&month("2023-04")),
+      dates(&["2023-04-30"])
+    );
+    assert_eq!(
+      template.occurrences(&month("2023-05")),
+      dates(&["2023-05-31"])
+    );
+    assert!(template.occurrences(&month("2022-12")).is_empty());
+  }
+
+  #[test]
+  fn weekly_keeps_the_weekday_across_months() {
+    let template = template(Frequency::WEEKLY, "2023-01-31");
+
+    assert_eq!(
+      template.occurrences(&month("2023-01")),
+      dates(&["2023-01-31"])
+    );
+    assert_eq!(
+      template.occurrences(&month("2023-02")),
+      dates(&["2023-02-07", "2023-02-14", "2023-02-21", "2023-02-28"])
+    );
+    assert_eq!(
+      template.occurrences(&month("2023-03")),
+      dates(&["2023-03-07", "2023-03-14", "2023-03-21", "2023-03-28"])
+    );
+  }
+
+  #[test]
+  fn weekly_stops_at_the_end_date() {
+    let mut template = template(Frequency::WEEKLY, "2023-03-15");
+    template.end = Some(date("2023-03-22"));
+
+    assert_eq!(
+      template.occurrences(&month("2023-03")),
+      dates(&["2023-03-15", "2023-03-22"])
+    );
+    assert!(template.occurrences(&month("2023-04")).is_empty());
+  }
+
+  #[test]
+  fn yearly_on_a_leap_day_falls_on_february_28th() {
+    let template = template(Frequency::YEARLY, "2020-02-29");
+
+    assert_eq!(
+      template.occurrences(&month("2023-02")),
+      dates(&["2023-02-28"])
+    );
+    assert!(template.occurrences(&month("2023-03")).is_empty());
+    assert_eq!(
+      template.next_occurrence(date("2023-03-01")),
+      Some(date("2024-02-29"))
+    );
+  }
+
+  #[test]
+  fn pending_skips_generated_occurrences() {
+    let mut template = template(Frequency::WEEKLY, "2023-03-15");
+    template.generated.insert(date("2023-03-15"));
+
+    assert_eq!(
+      template.pending(&month("2023-03")),
+      dates(&["2023-03-22", "2023-03-29"])
+    );
+  }
+}